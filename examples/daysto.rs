@@ -1,10 +1,13 @@
 use holiday::*;
 use two_timer::parse;
 
+use std::io::Read;
+
 const USAGE: &str = r#"daysto: Command line date counter. How many days until...?
 
 USAGE:
-    daysto <date>
+    daysto <date>...
+    daysto -            Read one date per line from stdin
 
 EXAMPLES:
     daysto '2020-3-31'
@@ -12,8 +15,11 @@ EXAMPLES:
     daysto 'March 31, 2021'
     daysto 'November 2'
     daysto 'This Friday'
+    daysto 'Next Friday'
     daysto Christmas
     daysto Thanksgiving
+    daysto '4th Thursday in November'
+    daysto '4th Thursday in November 2030'
     daysto '1 day after tomorrow'
     daysto '30 days ago'"#;
 
@@ -31,26 +37,40 @@ fn main() {
     }
 
     for arg in args {
-        match arg.parse::<Holiday<_>>() {
-            Ok(holiday) => {
-                let next = holiday.after_today();
-                let days = days_to(next);
-                println!("Days until {} ({}): {}", holiday.name(), next.format(FORMAT), days);
-            },
-            Err(_) => {
-                match parse(arg.as_str(), None) {
-                    Ok((first, _second, _is_range)) => {
-                        let days = days_to(first.date());
-                        println!("Days until {}: {}", first.format(FORMAT), days);
-                    }
-                    Err(_) => eprintln!("Unknown holiday: '{}'", arg),
-                }
+        if arg == "-" {
+            let mut input = String::new();
+            if let Err(err) = std::io::stdin().read_to_string(&mut input) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+            for (line, holiday) in input.lines().zip(parse_many(&input)) {
+                count_days(line.trim(), holiday);
+            }
+        } else {
+            count_days(&arg, arg.parse::<Holiday<String>>());
+        }
+    }
+}
+
+fn count_days(arg: &str, holiday: Result<Holiday<String>, HolidayError>) {
+    match holiday {
+        Ok(holiday) => {
+            println!("{}", holiday.countdown_string(Local::now().date_naive()));
+        },
+        Err(err) => {
+            let date = parse_date(arg)
+                .or_else(|_| parse_relative_weekday(arg, Local::now().date_naive()))
+                .ok()
+                .or_else(|| parse(arg, None).ok().map(|(first, _second, _is_range)| first.date()));
+            match date {
+                Some(date) => println!("Days until {}: {}", date.format(FORMAT), days_to(date)),
+                None => eprintln!("{}", err),
             }
         }
     }
 }
 
 fn days_to(date: NaiveDate) -> i64 {
-    (date - Local::today().naive_local()).num_days()
+    (date - Local::now().date_naive()).num_days()
 }
 
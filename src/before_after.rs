@@ -7,12 +7,26 @@ use crate::*;
 /// Trait to determine the next and previous occurrence of dates (successor and predecessor).
 /// The next occurrence should include the current date, the previous occurrence should exclude the current date.
 pub trait BeforeAfterDate {
-    /// The next occurrence after a given date
+    /// The next occurrence after a given date. Panics if there is no occurrence in the
+    /// representable date range; see `try_after`.
     fn after(&self, date: &NaiveDate) -> NaiveDate;
 
-    /// The previous occurrence before a given date
+    /// The previous occurrence before a given date. Panics if there is no occurrence in the
+    /// representable date range; see `try_before`.
     fn before(&self, date: &NaiveDate) -> NaiveDate;
 
+    /// The next occurrence after a given date, or `None` if there is no occurrence in the
+    /// representable date range, e.g., for a date rule looked up in a table
+    fn try_after(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        Some(self.after(date))
+    }
+
+    /// The previous occurrence before a given date, or `None` if there is no occurrence in the
+    /// representable date range
+    fn try_before(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        Some(self.before(date))
+    }
+
     /// The next occurrence including today (successor)
     fn after_today(&self) -> NaiveDate {
         self.after(&Local::today().naive_local())
@@ -36,106 +50,141 @@ pub trait BeforeAfterDate {
 
 impl<S: ToString> BeforeAfterDate for Holiday<S> {
     fn after(&self, date: &NaiveDate) -> NaiveDate {
-        self.date.after(date)
+        self.try_after(date).expect("no occurrence after date")
     }
 
     fn before(&self, date: &NaiveDate) -> NaiveDate {
-        self.date.before(date)
+        self.try_before(date).expect("no occurrence before date")
+    }
+
+    fn try_after(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        let date = match self.valid_start() {
+            Some(start) if *date < start => start,
+            _ => *date,
+        };
+        match self.recurrence() {
+            Recurrence::Annual => self.date.try_after(&date),
+            _ => after_by_year(&date, |year| self.occurrences_in_year(year)),
+        }
+    }
+
+    fn try_before(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        let before = match self.recurrence() {
+            Recurrence::Annual => self.date.try_before(date),
+            _ => before_by_year(date, |year| self.occurrences_in_year(year)),
+        };
+        before.filter(|before| !matches!(self.valid_start(), Some(start) if *before < start))
     }
 }
 
 impl BeforeAfterDate for HolidayDate {
     fn after(&self, date: &NaiveDate) -> NaiveDate {
+        self.try_after(date).expect("no occurrence after date")
+    }
+
+    fn before(&self, date: &NaiveDate) -> NaiveDate {
+        self.try_before(date).expect("no occurrence before date")
+    }
+
+    fn try_after(&self, date: &NaiveDate) -> Option<NaiveDate> {
         match self {
-            HolidayDate::FixedDate(day_of_month) => day_of_month.after(date),
-            HolidayDate::NthDate(nth) => nth.after(date),
+            HolidayDate::FixedDate(day_of_month) => day_of_month.try_after(date),
+            HolidayDate::NthDate(nth) => nth.try_after(date),
+            _ => after_by_year(date, |year| self.occurrences_in_year(year)),
         }
     }
 
-    fn before(&self, date: &NaiveDate) -> NaiveDate {
+    fn try_before(&self, date: &NaiveDate) -> Option<NaiveDate> {
         match self {
-            HolidayDate::FixedDate(day_of_month) => day_of_month.before(date),
-            HolidayDate::NthDate(nth) => nth.before(date),
+            HolidayDate::FixedDate(day_of_month) => day_of_month.try_before(date),
+            HolidayDate::NthDate(nth) => nth.try_before(date),
+            _ => before_by_year(date, |year| self.occurrences_in_year(year)),
         }
     }
 }
 
+/// Find the next occurrence of a date rule from its sorted occurrences in each year, including
+/// the given date, or `None` if there is none in the representable date range
+fn after_by_year<F: Fn(i32) -> Vec<NaiveDate>>(date: &NaiveDate, in_year: F) -> Option<NaiveDate> {
+    (date.year()..=NaiveDate::MAX.year())
+        .flat_map(in_year)
+        .find(|resolved| resolved >= date)
+}
+
+/// Find the previous occurrence of a date rule from its sorted occurrences in each year,
+/// excluding the given date, or `None` if there is none in the representable date range
+fn before_by_year<F: Fn(i32) -> Vec<NaiveDate>>(date: &NaiveDate, in_year: F) -> Option<NaiveDate> {
+    (NaiveDate::MIN.year()..=date.year())
+        .rev()
+        .flat_map(|year| in_year(year).into_iter().rev())
+        .find(|resolved| resolved < date)
+}
+
+/// A DayOfMonth is resolved directly in each year, so a date that only exists in some years, like
+/// February 29, skips the years without it.
 impl BeforeAfterDate for DayOfMonth {
     fn after(&self, date: &NaiveDate) -> NaiveDate {
-        let mut check_date = date.clone();
-        loop {
-            if self == &check_date {
-                break check_date;
-            } else {
-                check_date = check_date.succ();
-            }
-        }
+        self.try_after(date).expect("no occurrence after date")
     }
 
     fn before(&self, date: &NaiveDate) -> NaiveDate {
-        let mut check_date = date.clone().pred();
-        loop {
-            if self == &check_date {
-                break check_date;
-            } else {
-                check_date = check_date.pred();
-            }
-        }
+        self.try_before(date).expect("no occurrence before date")
+    }
+
+    fn try_after(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        after_by_year(date, |year| self.to_date(year).into_iter().collect())
+    }
+
+    fn try_before(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        before_by_year(date, |year| self.to_date(year).into_iter().collect())
     }
 }
 
+/// The search for an NthWeekdayOfMonth stops at the representable date range: `try_after` and
+/// `try_before` return `None` when there is no occurrence.
 impl BeforeAfterDate for NthWeekdayOfMonth {
     fn after(&self, date: &NaiveDate) -> NaiveDate {
-        let mut check_date = date.clone();
+        self.try_after(date).expect("no occurrence after date")
+    }
+
+    fn before(&self, date: &NaiveDate) -> NaiveDate {
+        self.try_before(date).expect("no occurrence before date")
+    }
+
+    fn try_after(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        let mut check_date = *date;
         loop {
             if self == &check_date {
-                break check_date;
-            } else {
-                if check_date.month() < self.month {
-                    check_date = check_date
-                        .with_day(1)
-                        .expect("invalid day: after::lt")
-                        .with_month(self.month as u32)
-                        .expect("invalid month: after::lt");
-                } else if check_date.month() > self.month {
-                    check_date = check_date
-                        .with_day(1)
-                        .expect("invalid day")
-                        .with_month(self.month as u32)
-                        .expect("invalid month: after::gt")
-                        .with_year(check_date.year() + 1)
-                        .expect("invalid year: after::gt");
-                } else {
-                    check_date = check_date.succ();
-                }
+                break Some(check_date);
             }
+
+            check_date = if check_date.month() < self.month {
+                check_date.with_day(1).and_then(|date| date.with_month(self.month as u32))
+            } else if check_date.month() > self.month {
+                NaiveDate::from_ymd_opt(check_date.year() + 1, self.month as u32, 1)
+            } else {
+                check_date.succ_opt()
+            }?;
         }
     }
 
-    fn before(&self, date: &NaiveDate) -> NaiveDate {
-        let mut check_date = date.clone().pred();
+    fn try_before(&self, date: &NaiveDate) -> Option<NaiveDate> {
+        let mut check_date = date.pred_opt()?;
         loop {
             if self == &check_date {
-                break check_date;
-            } else {
-                if check_date.month() > self.month {
-                    check_date = check_date
-                        .with_month(self.month as u32)
-                        .expect("invalid month: before::gt")
-                        .last_day_of_month();
-                } else if check_date.month() < self.month {
-                    check_date = check_date
-                        .with_day(1)
-                        .expect("invalid day: before::lt")
-                        .with_month(self.month as u32)
-                        .expect("invalid month: before::lt")
-                        .with_year(check_date.year() - 1)
-                        .expect("invalid year: before::lt")
-                        .last_day_of_month();
-                } else {
-                    check_date = check_date.pred();
-                }
+                break Some(check_date);
             }
+
+            // Clamp to the first of the month before changing months so the day always exists
+            check_date = if check_date.month() > self.month {
+                NaiveDate::from_ymd_opt(check_date.year(), self.month as u32, 1)
+                    .map(|date| date.last_day_of_month())
+            } else if check_date.month() < self.month {
+                NaiveDate::from_ymd_opt(check_date.year() - 1, self.month as u32, 1)
+                    .map(|date| date.last_day_of_month())
+            } else {
+                check_date.pred_opt()
+            }?;
         }
     }
 }
@@ -153,7 +202,10 @@ impl<D: Datelike> LastDayOfMonth for D {
             _ => (self.month() + 1, self.year()),
         };
 
-        NaiveDate::from_ymd(next_year, next_month, 1).pred()
+        // The last month of the last representable year has no following month
+        NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|date| date.pred_opt())
+            .unwrap_or(NaiveDate::MAX)
     }
 }
 
@@ -169,25 +221,86 @@ impl<D: Datelike> FirstDayOfMonth for D {
     }
 }
 
+/// Trait to determine if a date falls on a weekend
+pub trait IsWeekend: Datelike {
+    /// Determine if a date is a Saturday or Sunday
+    fn is_weekend(&self) -> bool {
+        matches!(self.weekday(), Weekday::Sat | Weekday::Sun)
+    }
+}
+
+impl<D: Datelike> IsWeekend for D {}
+
+/// Determine which calendar week of its month a date falls in
+pub trait WeekOfMonth: Datelike {
+    /// The 1-based calendar week of the month, with weeks starting on Sunday
+    fn week_of_month(&self) -> u32 {
+        self.week_of_month_from(WeekStart::default())
+    }
+
+    /// The 1-based calendar week of the month, with weeks starting on a given day
+    fn week_of_month_from(&self, week_start: WeekStart) -> u32 {
+        let first = self.first_day_of_month();
+        (self.day() - 1 + week_start.days_from(first.weekday())) / 7 + 1
+    }
+}
+
+impl<D: Datelike> WeekOfMonth for D {}
+
+/// Describe which occurrence of its weekday in its month a date is
+pub trait NthWeekdayLabel: Datelike {
+    /// A label like "4th Thursday" for a date. The fifth occurrence of a weekday is always the
+    /// last, so it is labeled like "Last Monday".
+    fn nth_weekday_label(&self) -> String {
+        let label = match (self.day() - 1) / 7 + 1 {
+            5 => "Last".to_string(),
+            nth => ordinal(nth),
+        };
+        format!("{} {}", label, weekday_name(self.weekday()))
+    }
+}
+
+impl<D: Datelike> NthWeekdayLabel for D {}
+
+/// Determine if a date falls in the same week as a Holiday
+pub trait IsHolidayWeek: Datelike {
+    /// Determine if an occurrence of a Holiday falls in the same week as a date, with weeks
+    /// starting on a given weekday
+    fn is_holiday_week<S: ToString>(&self, holiday: &Holiday<S>, week_start: Weekday) -> bool {
+        let date = match NaiveDate::from_ymd_opt(self.year(), self.month(), self.day()) {
+            Some(date) => date,
+            None => return false,
+        };
+        let days_into_week = (self.weekday().num_days_from_monday() + 7 - week_start.num_days_from_monday()) % 7;
+        let first = date - chrono::Duration::days(days_into_week as i64);
+        let last = first + chrono::Duration::days(6);
+        (first.year()..=last.year())
+            .flat_map(|year| holiday.occurrences_in_year(year))
+            .any(|occurrence| first <= occurrence && occurrence <= last)
+    }
+}
+
+impl<D: Datelike> IsHolidayWeek for D {}
+
 /// Trait to determine if a NaiveDate is the last weekday of the month
 pub trait IsLastWeekday: Datelike {
     /// Determine if a date is the last weekday of the month
     fn is_last_weekday(self) -> bool {
-        let mut count = 0;
-        let mut date = NaiveDate::from_ymd(self.year(), self.month(), self.day()).succ();
-        while date <= self.last_day_of_month().succ() {
-            if date.weekday() == self.weekday() {
-                count += 1;
-            }
-            date = date.succ();
+        match NaiveDate::from_ymd_opt(self.year(), self.month(), self.day()) {
+            Some(date) => is_last_weekday_of(&date, self.weekday()),
+            None => false,
         }
-
-        count == 0
     }
 }
 
 impl<D: Datelike> IsLastWeekday for D {}
 
+/// Determine if a date is the last occurrence of a given weekday in its month, e.g., whether
+/// May 31, 2021 is the last Monday in May. A date on a different weekday is never the last one.
+pub fn is_last_weekday_of(date: &NaiveDate, weekday: Weekday) -> bool {
+    date.weekday() == weekday && date.day() + 7 > date.last_day_of_month().day()
+}
+
 #[test]
 fn test_is_last_weekday() {
     let date = NaiveDate::from_ymd(2020, 7, 28);
@@ -202,6 +315,9 @@ fn test_is_last_weekday() {
     let date = NaiveDate::from_ymd(2021, 1, 1);
     assert!(!date.is_last_weekday());
 
+    let date = NaiveDate::from_ymd(2020, 5, 25);
+    assert!(date.is_last_weekday());
+
     let holiday = Holiday::new_nth("Last Tuesday in July", NthWeekday::Last, Weekday::Tue, 7);
     let holiday2 = Holiday::new_nth("Fourth Tuesday in July", NthWeekday::Fourth, Weekday::Tue, 7);
     let date = NaiveDate::from_ymd(2020, 7, 28);
@@ -210,6 +326,24 @@ fn test_is_last_weekday() {
     assert_ne!(holiday, holiday2);
 }
 
+#[test]
+fn last_weekday_of() {
+    let date = NaiveDate::from_ymd(2021, 5, 31);
+    assert!(is_last_weekday_of(&date, Weekday::Mon));
+    assert!(!is_last_weekday_of(&date, Weekday::Fri));
+    assert!(!is_last_weekday_of(&NaiveDate::from_ymd(2021, 5, 24), Weekday::Mon));
+
+    let memorial_day = Holiday::new_nth("Memorial Day", NthWeekday::Last, Weekday::Mon, 5);
+    assert_ne!(memorial_day, NaiveDate::from_ymd(2021, 6, 28));
+}
+
+#[test]
+fn nth_weekday_labels() {
+    assert_eq!(NaiveDate::from_ymd(2020, 11, 26).nth_weekday_label(), "4th Thursday");
+    assert_eq!(NaiveDate::from_ymd(2021, 5, 31).nth_weekday_label(), "Last Monday");
+    assert_eq!(NaiveDate::from_ymd(2021, 5, 3).nth_weekday_label(), "1st Monday");
+}
+
 #[test]
 fn test_last_day_of_month() {
     let date = NaiveDate::from_ymd(2020, 12, 31);
@@ -245,3 +379,54 @@ fn test_first_day_of_month() {
     let exp = NaiveDate::from_ymd(2020, 2, 1);
     assert_eq!(date.first_day_of_month(), exp);
 }
+
+#[test]
+fn test_week_of_month() {
+    // March 2021 starts on a Monday
+    assert_eq!(NaiveDate::from_ymd(2021, 3, 1).week_of_month(), 1);
+    assert_eq!(NaiveDate::from_ymd(2021, 3, 6).week_of_month(), 1);
+    assert_eq!(NaiveDate::from_ymd(2021, 3, 7).week_of_month(), 2);
+    assert_eq!(NaiveDate::from_ymd(2021, 3, 31).week_of_month(), 5);
+
+    // May 2021 starts on a Saturday and spans six calendar weeks
+    assert_eq!(NaiveDate::from_ymd(2021, 5, 1).week_of_month(), 1);
+    assert_eq!(NaiveDate::from_ymd(2021, 5, 31).week_of_month(), 6);
+
+    assert_eq!(NaiveDate::from_ymd(2021, 3, 7).week_of_month_from(WeekStart::Monday), 1);
+    assert_eq!(NaiveDate::from_ymd(2021, 3, 31).week_of_month_from(WeekStart::Monday), 5);
+}
+
+#[test]
+fn nth_weekday_near_bounds() {
+    use holidays::united_states::THANKSGIVING;
+    let last = THANKSGIVING.last_date();
+    let dates = THANKSGIVING
+        .iter()
+        .at(NaiveDate::from_ymd(chrono::MAX_DATE.year() - 2, 1, 1))
+        .collect::<Vec<_>>();
+    assert_eq!(dates.len(), 3);
+    assert_eq!(dates.last(), Some(&last));
+    assert_eq!(THANKSGIVING.try_after(&last.succ()), None);
+
+    let first = THANKSGIVING.first_date();
+    assert_eq!(THANKSGIVING.try_before(&first), None);
+    assert_eq!(THANKSGIVING.iter().at(first).next_back(), None);
+    assert_eq!(THANKSGIVING.before(&NaiveDate::from_ymd(2022, 1, 1)), NaiveDate::from_ymd(2021, 11, 25));
+    assert_eq!(chrono::MAX_DATE.naive_local().last_day_of_month(), chrono::MAX_DATE.naive_local());
+}
+
+#[test]
+fn test_is_holiday_week() {
+    use holidays::{global::NEW_YEARS_DAY, united_states::THANKSGIVING};
+    // Thanksgiving 2021 is Thursday, November 25
+    assert!(NaiveDate::from_ymd(2021, 11, 21).is_holiday_week(&THANKSGIVING, Weekday::Sun));
+    assert!(NaiveDate::from_ymd(2021, 11, 27).is_holiday_week(&THANKSGIVING, Weekday::Sun));
+    assert!(!NaiveDate::from_ymd(2021, 11, 20).is_holiday_week(&THANKSGIVING, Weekday::Sun));
+    assert!(!NaiveDate::from_ymd(2021, 11, 28).is_holiday_week(&THANKSGIVING, Weekday::Sun));
+
+    assert!(NaiveDate::from_ymd(2021, 11, 28).is_holiday_week(&THANKSGIVING, Weekday::Mon));
+    assert!(!NaiveDate::from_ymd(2021, 11, 21).is_holiday_week(&THANKSGIVING, Weekday::Mon));
+
+    // New Year's Day 2022 is a Saturday, in the same week as December 27, 2021
+    assert!(NaiveDate::from_ymd(2021, 12, 27).is_holiday_week(&NEW_YEARS_DAY, Weekday::Sun));
+}
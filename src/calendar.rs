@@ -0,0 +1,434 @@
+//! # HolidayCalendar
+//!
+//! A `HolidayCalendar` is a collection of holidays that can be queried by date
+
+use crate::*;
+
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+/// The differences between two HolidayCalendars, matching Holidays by name
+#[derive(Debug, Clone)]
+pub struct CalendarDiff<'c, S> {
+    /// Holidays in the new calendar that are not in the old calendar
+    pub added: Vec<&'c Holiday<S>>,
+    /// Holidays in the old calendar that are not in the new calendar
+    pub removed: Vec<&'c Holiday<S>>,
+    /// Holidays in both calendars with a different date rule, as (old, new) pairs
+    pub changed: Vec<(&'c Holiday<S>, &'c Holiday<S>)>,
+}
+
+impl<'c, S> CalendarDiff<'c, S> {
+    /// Determine if the calendars have no differences
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A collection of Holidays, each with an optional region tag
+#[derive(Debug, Clone)]
+pub struct HolidayCalendar<S> {
+    holidays: Vec<Holiday<S>>,
+    tags: Vec<Option<String>>,
+}
+
+impl<S: ToString> HolidayCalendar<S> {
+    /// Creates a new empty HolidayCalendar
+    pub fn new() -> Self {
+        HolidayCalendar { holidays: Vec::new(), tags: Vec::new() }
+    }
+
+    /// Add a Holiday to the calendar
+    pub fn push(&mut self, holiday: Holiday<S>) {
+        self.holidays.push(holiday);
+        self.tags.push(None);
+    }
+
+    /// Add a Holiday to the calendar with a region tag
+    pub fn push_tagged(&mut self, holiday: Holiday<S>, tag: &str) {
+        self.holidays.push(holiday);
+        self.tags.push(Some(tag.to_string()));
+    }
+
+    /// Add all the Holidays in another calendar to this one, tagging each with a region.
+    /// Any existing tags in the other calendar are replaced.
+    pub fn merge(&mut self, other: HolidayCalendar<S>, tag: &str) {
+        for holiday in other.holidays {
+            self.push_tagged(holiday, tag);
+        }
+    }
+
+    /// Returns a slice of all the Holidays in the calendar
+    pub fn holidays(&self) -> &[Holiday<S>] {
+        &self.holidays
+    }
+
+    /// Returns the first Holiday in the calendar that occurs on a given date
+    pub fn is_holiday(&self, date: &NaiveDate) -> Option<&Holiday<S>> {
+        self.holidays.iter().find(|holiday| *holiday == date)
+    }
+
+    /// Returns the soonest Holiday on or after a given date, its date, and the number of days until
+    /// it. Holidays on the same date are returned in calendar order. Returns `None` if no Holiday
+    /// occurs on or after the date, e.g., if the calendar is empty.
+    pub fn next_from(&self, date: NaiveDate) -> Option<(NaiveDate, &Holiday<S>, i64)> {
+        self.holidays
+            .iter()
+            .filter_map(|holiday| holiday.try_after(&date).map(|next| (next, holiday)))
+            .min_by_key(|(next, _holiday)| *next)
+            .map(|(next, holiday)| (next, holiday, (next - date).num_days()))
+    }
+
+    /// Returns the first date on or after a given date that is neither a weekend nor a holiday,
+    /// skipping any run of consecutive holidays and weekends. Panics if there is no such date
+    /// before the end of the range `NaiveDate` supports.
+    pub fn reopen_date(&self, from: NaiveDate) -> NaiveDate {
+        let mut date = from;
+        while date.is_weekend() || self.is_holiday(&date).is_some() {
+            date = date.succ_opt().expect("no working day after date");
+        }
+        date
+    }
+
+    /// Returns every occurrence of every Holiday in the calendar in a given year, sorted by date.
+    /// Holidays that do not occur that year, like Leap Day in a common year, are left out.
+    pub fn holidays_in_year(&self, year: i32) -> Vec<(NaiveDate, &Holiday<S>)> {
+        let mut dates = self
+            .holidays
+            .iter()
+            .flat_map(|holiday| holiday.occurrences_in_year(year).into_iter().map(move |date| (date, holiday)))
+            .collect::<Vec<_>>();
+        dates.sort_by_key(|(date, _holiday)| *date);
+        dates
+    }
+
+    /// Returns the 0-based position of a date among the dates of the Holidays in a given year
+    /// (see `holidays_in_year`), or `None` if no Holiday falls on the date that year
+    pub fn index_in_year(&self, date: &NaiveDate, year: i32) -> Option<usize> {
+        self.holidays_in_year(year).iter().position(|(holiday_date, _holiday)| holiday_date == date)
+    }
+
+    /// Returns every occurrence of every Holiday in the calendar between two dates (inclusive),
+    /// sorted by date
+    pub fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<(NaiveDate, &Holiday<S>)> {
+        let mut dates = Vec::new();
+        for holiday in self.holidays.iter() {
+            let mut next = holiday.try_after(&start);
+            while let Some(date) = next.filter(|date| *date <= end) {
+                dates.push((date, holiday));
+                next = date.succ_opt().and_then(|date| holiday.try_after(&date));
+            }
+        }
+        dates.sort_by_key(|(date, _holiday)| *date);
+        dates
+    }
+
+    /// Compute every occurrence of every Holiday in a range of years (inclusive), indexed by date.
+    ///
+    /// The map holds one entry per date with at least one Holiday, so its size grows with the
+    /// number of holidays times the number of years: a calendar of 20 holidays precomputed over
+    /// 200 years holds about 4,000 dates.
+    pub fn precompute(&self, year_start: i32, year_end: i32) -> BTreeMap<NaiveDate, Vec<&Holiday<S>>> {
+        let mut map = BTreeMap::new();
+        // Years outside the range `NaiveDate` supports are skipped
+        let start = NaiveDate::from_ymd_opt(year_start.max(NaiveDate::MIN.year()), 1, 1);
+        let end = NaiveDate::from_ymd_opt(year_end.min(NaiveDate::MAX.year()), 12, 31);
+        if let (Some(start), Some(end)) = (start, end) {
+            for (date, holiday) in self.holidays_between(start, end) {
+                map.entry(date).or_insert_with(Vec::new).push(holiday);
+            }
+        }
+        map
+    }
+
+    /// Format every occurrence of every Holiday in a given year as a list grouped by month.
+    /// Example: "November:\n  11 Veteran's Day (Thu)\n  25 Thanksgiving (Thu)"
+    pub fn print_year(&self, year: i32) -> String {
+        let mut dates = self
+            .holidays
+            .iter()
+            .flat_map(|holiday| holiday.occurrences_in_year(year).into_iter().map(move |date| (date, holiday)))
+            .collect::<Vec<_>>();
+        dates.sort_by_key(|(date, _holiday)| *date);
+
+        let mut lines = Vec::new();
+        let mut month = None;
+        for (date, holiday) in dates {
+            if month != Some(date.month()) {
+                month = Some(date.month());
+                lines.push(date.format("%B:").to_string());
+            }
+            lines.push(format!("  {:2} {} ({})", date.day(), holiday.name.to_string(), date.format("%a")));
+        }
+        lines.join("\n")
+    }
+
+    /// Returns the date and region tag of every occurrence of every Holiday in the calendar in a
+    /// given year, sorted by date. Holidays that do not occur that year are left out.
+    pub fn holidays_in_year_tagged(&self, year: i32) -> Vec<(NaiveDate, &Holiday<S>, Option<&str>)> {
+        let mut dates = self
+            .holidays
+            .iter()
+            .zip(self.tags.iter())
+            .flat_map(|(holiday, tag)| {
+                holiday.occurrences_in_year(year).into_iter().map(move |date| (date, holiday, tag.as_deref()))
+            })
+            .collect::<Vec<_>>();
+        dates.sort_by_key(|(date, _holiday, _tag)| *date);
+        dates
+    }
+
+    /// Compare this calendar against an older calendar, matching Holidays by name: Holidays only
+    /// in this calendar are added, Holidays only in the older calendar are removed.
+    pub fn diff<'c>(&'c self, old: &'c HolidayCalendar<S>) -> CalendarDiff<'c, S> {
+        let find = |calendar: &'c HolidayCalendar<S>, name: String| {
+            calendar.holidays.iter().find(|holiday| holiday.name.to_string() == name)
+        };
+
+        let mut diff = CalendarDiff { added: Vec::new(), removed: Vec::new(), changed: Vec::new() };
+        for holiday in self.holidays.iter() {
+            match find(old, holiday.name.to_string()) {
+                None => diff.added.push(holiday),
+                Some(old_holiday) if !old_holiday.same_date(holiday) => diff.changed.push((old_holiday, holiday)),
+                Some(_) => {}
+            }
+        }
+        for holiday in old.holidays.iter() {
+            if find(self, holiday.name.to_string()).is_none() {
+                diff.removed.push(holiday);
+            }
+        }
+        diff
+    }
+
+    /// The fraction of a regular work day worked on a given date: 1.0 is a full work day, 0.5 is
+    /// a half day, and 0.0 is a day off. Weekends are not taken into account.
+    pub fn work_fraction(&self, date: &NaiveDate) -> f32 {
+        self.holidays
+            .iter()
+            .filter(|holiday| *holiday == date)
+            .map(|holiday| holiday.observance().work_fraction())
+            .fold(1.0, f32::min)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<S: ToString + Sync> HolidayCalendar<S> {
+    /// Compute every occurrence of every Holiday in a range of years (inclusive), indexed by date,
+    /// resolving each year in parallel. The result is the same as `precompute`.
+    pub fn par_precompute(&self, year_start: i32, year_end: i32) -> BTreeMap<NaiveDate, Vec<&Holiday<S>>> {
+        use rayon::prelude::*;
+
+        let years = (year_start..=year_end)
+            .into_par_iter()
+            .filter_map(|year| Some((NaiveDate::from_ymd_opt(year, 1, 1)?, NaiveDate::from_ymd_opt(year, 12, 31)?)))
+            .map(|(start, end)| self.holidays_between(start, end))
+            .collect::<Vec<_>>();
+
+        // Merge in year and calendar order so the result does not depend on thread scheduling
+        let mut map = BTreeMap::new();
+        for (date, holiday) in years.into_iter().flatten() {
+            map.entry(date).or_insert_with(Vec::new).push(holiday);
+        }
+        map
+    }
+}
+
+impl<S: ToString> Default for HolidayCalendar<S> {
+    fn default() -> Self {
+        HolidayCalendar::new()
+    }
+}
+
+impl<S: ToString> From<Vec<Holiday<S>>> for HolidayCalendar<S> {
+    fn from(holidays: Vec<Holiday<S>>) -> Self {
+        let tags = vec![None; holidays.len()];
+        HolidayCalendar { holidays, tags }
+    }
+}
+
+impl<S: ToString> FromIterator<Holiday<S>> for HolidayCalendar<S> {
+    fn from_iter<I: IntoIterator<Item = Holiday<S>>>(iter: I) -> Self {
+        HolidayCalendar::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use holidays::global::*;
+
+    #[test]
+    fn half_day_work_fraction() {
+        let calendar = vec![
+            CHRISTMAS_EVE.with_observance(Observance::HalfDay),
+            CHRISTMAS,
+        ]
+        .into_iter()
+        .collect::<HolidayCalendar<_>>();
+
+        assert_eq!(calendar.work_fraction(&NaiveDate::from_ymd(2021, 12, 23)), 1.0);
+        assert_eq!(calendar.work_fraction(&NaiveDate::from_ymd(2021, 12, 24)), 0.5);
+        assert_eq!(calendar.work_fraction(&NaiveDate::from_ymd(2021, 12, 25)), 0.0);
+    }
+
+    #[test]
+    fn holidays_between_years() {
+        use holidays::united_states::*;
+        let calendar = HolidayCalendar::from(vec![NEW_YEARS_DAY, CHRISTMAS, THANKSGIVING, HALLOWEEN]);
+
+        let between = calendar
+            .holidays_between(NaiveDate::from_ymd(2020, 11, 1), NaiveDate::from_ymd(2021, 1, 1))
+            .into_iter()
+            .map(|(date, holiday)| (date, *holiday.name()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(between, vec![
+            (NaiveDate::from_ymd(2020, 11, 26), "Thanksgiving"),
+            (NaiveDate::from_ymd(2020, 12, 25), "Christmas"),
+            (NaiveDate::from_ymd(2021, 1, 1), "New Year's Day"),
+        ]);
+    }
+
+    #[test]
+    fn precompute_years() {
+        let calendar = holidays::united_states::calendar();
+        let map = calendar.precompute(2020, 2022);
+
+        let thanksgivings = map
+            .iter()
+            .filter(|(_date, holidays)| holidays.contains(&&holidays::united_states::THANKSGIVING))
+            .map(|(date, _holidays)| *date)
+            .collect::<Vec<_>>();
+
+        assert_eq!(thanksgivings, vec![
+            NaiveDate::from_ymd(2020, 11, 26),
+            NaiveDate::from_ymd(2021, 11, 25),
+            NaiveDate::from_ymd(2022, 11, 24),
+        ]);
+
+        assert!(calendar.precompute(i32::MAX - 1, i32::MAX).is_empty());
+    }
+
+    #[test]
+    fn diff_calendars() {
+        use holidays::united_states::*;
+        let full = holidays::united_states::calendar();
+        let missing = ALL
+            .iter()
+            .filter(|holiday| **holiday != VETERANS_DAY)
+            .cloned()
+            .collect::<HolidayCalendar<_>>();
+
+        let diff = missing.diff(&full);
+        assert_eq!(diff.removed, vec![&VETERANS_DAY]);
+        assert!(diff.added.is_empty());
+        assert!(diff.changed.is_empty());
+
+        let moved = HolidayCalendar::from(vec![THANKSGIVING.shift_by(1).with_name("Thanksgiving")]);
+        let diff = moved.diff(&full);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].0, &THANKSGIVING);
+        assert!(full.diff(&full).is_empty());
+    }
+
+    #[test]
+    fn print_year() {
+        use holidays::united_states::*;
+        let calendar = HolidayCalendar::from(vec![THANKSGIVING, VETERANS_DAY, INDEPENDENCE_DAY]);
+        let printed = calendar.print_year(2021);
+        assert!(printed.contains("  25 Thanksgiving (Thu)"));
+        assert_eq!(printed, "July:\n   4 Independence Day (Sun)\nNovember:\n  11 Veteran's Day (Thu)\n  25 Thanksgiving (Thu)");
+    }
+
+    #[test]
+    fn holidays_in_common_year() {
+        let calendar = holidays::global::calendar();
+        assert!(calendar.holidays_in_year(2021).iter().all(|(date, _holiday)| date.year() == 2021));
+        assert!(!calendar.holidays_in_year(2021).iter().any(|(_date, holiday)| **holiday == LEAP_DAY));
+        assert!(calendar.holidays_in_year(2020).contains(&(NaiveDate::from_ymd(2020, 2, 29), &LEAP_DAY)));
+
+        assert!(holidays::full_moon::calendar().holidays_in_year(2031).is_empty());
+
+        // Islamic New Year falls twice in 2008
+        let islamic = HolidayCalendar::from(vec![holidays::islamic::ISLAMIC_NEW_YEAR]);
+        assert_eq!(islamic.holidays_in_year(2008).len(), 2);
+    }
+
+    #[test]
+    fn next_from_date() {
+        let from = NaiveDate::from_ymd(2021, 12, 1);
+        let mut calendar = holidays::united_states::calendar();
+        assert_eq!(
+            calendar.next_from(from),
+            Some((NaiveDate::from_ymd(2021, 12, 26), &holidays::united_states::KWANZAA, 25))
+        );
+
+        calendar.merge(holidays::global::calendar(), "global");
+        let from = NaiveDate::from_ymd(2021, 12, 20);
+        assert_eq!(calendar.next_from(from), Some((NaiveDate::from_ymd(2021, 12, 24), &CHRISTMAS_EVE, 4)));
+        assert_eq!(calendar.next_from(NaiveDate::from_ymd(2021, 12, 25)).map(|next| next.1), Some(&CHRISTMAS));
+
+        assert_eq!(HolidayCalendar::<&str>::new().next_from(from), None);
+        assert_eq!(holidays::full_moon::calendar().next_from(NaiveDate::from_ymd(2031, 1, 1)), None);
+    }
+
+    #[test]
+    fn index_of_holiday_in_year() {
+        let calendar = holidays::united_states::calendar();
+        assert_eq!(calendar.index_in_year(&NaiveDate::from_ymd(2021, 1, 18), 2021), Some(0));
+        assert_eq!(calendar.index_in_year(&NaiveDate::from_ymd(2021, 7, 4), 2021), Some(12));
+        assert_eq!(calendar.index_in_year(&NaiveDate::from_ymd(2021, 7, 5), 2021), None);
+
+        let calendar = HolidayCalendar::from(vec![NEW_YEARS_DAY, LEAP_DAY, CHRISTMAS]);
+        assert_eq!(calendar.index_in_year(&NaiveDate::from_ymd(2024, 2, 29), 2021), None);
+        assert_eq!(calendar.index_in_year(&NaiveDate::from_ymd(2021, 12, 25), 2021), Some(1));
+        assert_eq!(calendar.index_in_year(&NaiveDate::from_ymd(2024, 12, 25), 2024), Some(2));
+    }
+
+    #[test]
+    fn reopen_after_closure() {
+        let calendar = HolidayCalendar::from(vec![
+            CHRISTMAS_EVE,
+            CHRISTMAS,
+            holidays::united_kingdom::BOXING_DAY,
+            Holiday::new_fixed("Office Closed", December, 27),
+        ]);
+
+        // Friday, December 24 through Monday, December 27, 2021
+        assert_eq!(calendar.reopen_date(NaiveDate::from_ymd(2021, 12, 24)), NaiveDate::from_ymd(2021, 12, 28));
+        assert_eq!(calendar.reopen_date(NaiveDate::from_ymd(2021, 12, 26)), NaiveDate::from_ymd(2021, 12, 28));
+        assert_eq!(calendar.reopen_date(NaiveDate::from_ymd(2021, 12, 23)), NaiveDate::from_ymd(2021, 12, 23));
+        assert_eq!(calendar.reopen_date(NaiveDate::from_ymd(2021, 12, 18)), NaiveDate::from_ymd(2021, 12, 20));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_precompute_matches_serial() {
+        let mut calendar = holidays::united_states::calendar();
+        calendar.merge(holidays::global::calendar(), "global");
+        calendar.merge(holidays::islamic::calendar(), "islamic");
+        assert_eq!(calendar.par_precompute(2000, 2050), calendar.precompute(2000, 2050));
+    }
+
+    #[test]
+    fn merge_tagged() {
+        let mut calendar = HolidayCalendar::new();
+        calendar.merge(holidays::united_states::calendar(), "US");
+        calendar.merge(holidays::united_kingdom::calendar(), "UK");
+
+        let tagged = calendar.holidays_in_year_tagged(2021);
+        let tag_of = |name: &str| {
+            tagged
+                .iter()
+                .find(|(_date, holiday, _tag)| *holiday.name() == name)
+                .and_then(|(_date, _holiday, tag)| *tag)
+        };
+
+        assert_eq!(tag_of("Thanksgiving"), Some("US"));
+        assert_eq!(tag_of("Boxing Day"), Some("UK"));
+
+        calendar.merge(holidays::global::calendar(), "Global");
+        assert!(calendar.holidays_in_year_tagged(2021).iter().all(|(date, _holiday, _tag)| date.year() == 2021));
+    }
+}
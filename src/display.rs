@@ -0,0 +1,143 @@
+//! Human-readable formatting of holidays and date rules.
+//!
+//! Fixed dates and nth weekdays are formatted so they can be parsed back with `FromStr`: a
+//! `Holiday` formats as "Thanksgiving (4th Thursday in November)". Other date rules, like
+//! Easter-relative and lunar dates, are formatted with `HolidayDate::recurrence_desc` and do not
+//! round trip through the parser.
+//!
+//! Date rules can be rendered in other languages with a custom `HolidayFormatter`, see
+//! `Holiday::display_with`.
+
+use crate::*;
+
+use std::fmt;
+
+impl fmt::Display for DayOfMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} {}", self.month, self.day)
+    }
+}
+
+impl fmt::Display for NthWeekdayOfMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nth = match self.nth {
+            NthWeekday::Last => "last".to_string(),
+            nth => ordinal(nth as u32),
+        };
+        write!(f, "{} {} in {:?}", nth, weekday_name(self.weekday), self.month)
+    }
+}
+
+/// Renders a date rule as text, e.g., in a given language
+pub trait HolidayFormatter {
+    /// Render a date rule, e.g., "4th Thursday in November"
+    fn format(&self, date: &HolidayDate) -> String;
+}
+
+/// The English `HolidayFormatter` used by `Display`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EnglishFormatter;
+
+impl HolidayFormatter for EnglishFormatter {
+    fn format(&self, date: &HolidayDate) -> String {
+        match date {
+            FixedDate(day_of_month) => day_of_month.to_string(),
+            NthDate(nth) => nth.to_string(),
+            _ => date.recurrence_desc(),
+        }
+    }
+}
+
+/// A Holiday formatted with a `HolidayFormatter`, see `Holiday::display_with`
+pub struct DisplayWith<'a, S, F> {
+    holiday: &'a Holiday<S>,
+    formatter: &'a F,
+}
+
+impl<S: ToString> Holiday<S> {
+    /// Format the Holiday like `Display`, rendering its date rule with a given formatter
+    pub fn display_with<'a, F: HolidayFormatter>(&'a self, formatter: &'a F) -> DisplayWith<'a, S, F> {
+        DisplayWith { holiday: self, formatter }
+    }
+}
+
+impl<'a, S: ToString, F: HolidayFormatter> fmt::Display for DisplayWith<'a, S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.holiday.name.to_string(), self.formatter.format(&self.holiday.date))
+    }
+}
+
+impl fmt::Display for HolidayDate {
+    /// The alternate form (`{:#}`) renders fixed dates and nth weekdays numerically, e.g.,
+    /// "12-25" and "11/4-Thu", with "L" for the last weekday of the month: "5/L-Mon". Other date
+    /// rules render the same in both forms.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FixedDate(day_of_month) if f.alternate() => {
+                write!(f, "{:02}-{:02}", day_of_month.month as u32, day_of_month.day)
+            }
+            NthDate(nth) if f.alternate() => {
+                let n = match nth.nth {
+                    NthWeekday::Last => "L".to_string(),
+                    n => (n as u32).to_string(),
+                };
+                write!(f, "{}/{}-{}", nth.month as u32, n, nth.weekday)
+            }
+            _ => f.write_str(&EnglishFormatter.format(self)),
+        }
+    }
+}
+
+impl<S: ToString> fmt::Display for Holiday<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.display_with(&EnglishFormatter).fmt(f)
+    }
+}
+
+#[test]
+fn display_round_trip() {
+    let builtins = holidays::united_states::ALL.iter().chain(holidays::global::ALL);
+    for holiday in builtins.filter(|holiday| matches!(holiday.date, FixedDate(_) | NthDate(_))) {
+        let parsed = holiday.to_string().parse::<Holiday<String>>().unwrap();
+        assert!(parsed.same_date(holiday), "{} did not round trip", holiday);
+        assert_eq!(parsed.name(), holiday.name());
+    }
+
+    assert_eq!(holidays::united_states::THANKSGIVING.to_string(), "Thanksgiving (4th Thursday in November)");
+    assert_eq!(holidays::united_states::MEMORIAL_DAY.to_string(), "Memorial Day (last Monday in May)");
+    assert_eq!(holidays::global::CHRISTMAS.to_string(), "Christmas (December 25)");
+}
+
+#[test]
+fn display_alternate() {
+    use holidays::{global::*, united_states::*};
+    assert_eq!(format!("{:#}", CHRISTMAS.date), "12-25");
+    assert_eq!(format!("{}", CHRISTMAS.date), "December 25");
+    assert_eq!(format!("{:#}", INDEPENDENCE_DAY.date), "07-04");
+    assert_eq!(format!("{:#}", THANKSGIVING.date), "11/4-Thu");
+    assert_eq!(format!("{}", THANKSGIVING.date), "4th Thursday in November");
+    assert_eq!(format!("{:#}", MEMORIAL_DAY.date), "5/L-Mon");
+    assert_eq!(format!("{:#}", EASTER.date), format!("{}", EASTER.date));
+}
+
+#[test]
+fn custom_formatter() {
+    struct German;
+    impl HolidayFormatter for German {
+        fn format(&self, date: &HolidayDate) -> String {
+            const MONTHS: [&str; 12] = [
+                "Januar", "Februar", "März", "April", "Mai", "Juni",
+                "Juli", "August", "September", "Oktober", "November", "Dezember",
+            ];
+            match date {
+                FixedDate(day_of_month) => format!("{}. {}", day_of_month.day, MONTHS[day_of_month.month as usize - 1]),
+                _ => EnglishFormatter.format(date),
+            }
+        }
+    }
+
+    let christmas = holidays::germany::CHRISTMAS;
+    assert_eq!(German.format(&christmas.date), "25. Dezember");
+    assert_eq!(christmas.display_with(&German).to_string(), format!("{} (25. Dezember)", christmas.name()));
+    assert_eq!(holidays::global::CHRISTMAS.display_with(&EnglishFormatter).to_string(), "Christmas (December 25)");
+}
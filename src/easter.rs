@@ -0,0 +1,46 @@
+//! # Easter
+//!
+//! The date of Easter Sunday in the Gregorian calendar, from which many movable feasts are
+//! counted. Holidays relative to Easter use `HolidayDate::EasterRelative`, which calls
+//! `easter_sunday` once per year; compute it directly to resolve several such holidays at once.
+
+use crate::*;
+
+/// The first year supported by `easter_sunday`: 1583, the first full year of the Gregorian calendar
+pub const MIN_SUPPORTED_YEAR: i32 = 1583;
+
+/// The date of Easter Sunday in a given year, using the anonymous Gregorian algorithm (Meeus,
+/// Jones, Butcher).
+///
+/// The algorithm follows the Gregorian computus, so it is valid from 1583 (`MIN_SUPPORTED_YEAR`)
+/// onward. Earlier years are extrapolated with the same rules and do not match the historical
+/// (Julian) dates of Easter; use `Holiday::try_in_year` to reject them. Returns `None` if the date
+/// is outside the representable date range.
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l).div_euclid(451);
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[test]
+fn easter_sundays() {
+    assert_eq!(easter_sunday(2020), Some(NaiveDate::from_ymd(2020, 4, 12)));
+    assert_eq!(easter_sunday(2021), Some(NaiveDate::from_ymd(2021, 4, 4)));
+    assert_eq!(easter_sunday(2024), Some(NaiveDate::from_ymd(2024, 3, 31)));
+    assert_eq!(easter_sunday(2038), Some(NaiveDate::from_ymd(2038, 4, 25)));
+    assert_eq!(easter_sunday(1818), Some(NaiveDate::from_ymd(1818, 3, 22)));
+    assert_eq!(easter_sunday(chrono::MAX_DATE.year() + 1), None);
+}
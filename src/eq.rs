@@ -4,9 +4,32 @@ use std::cmp::Ordering;
 
 impl<S: ToString> PartialEq<NaiveDate> for Holiday<S> {
     fn eq(&self, date: &NaiveDate) -> bool {
-        match &self.date {
+        match self.recurrence {
+            Recurrence::Annual => &self.date == date && !matches!(self.valid_from, Some(year) if date.year() < year),
+            _ => self.occurrences_in_year(date.year()).contains(date),
+        }
+    }
+}
+
+impl<S: ToString> PartialEq<NaiveDateTime> for Holiday<S> {
+    fn eq(&self, datetime: &NaiveDateTime) -> bool {
+        self.datetime_in_year(datetime.year()) == *datetime
+    }
+}
+
+impl<S: ToString> PartialOrd<NaiveDateTime> for Holiday<S> {
+    /// Compare the Holiday's date and time in the year of a given datetime
+    fn partial_cmp(&self, datetime: &NaiveDateTime) -> Option<Ordering> {
+        self.datetime_in_year(datetime.year()).partial_cmp(datetime)
+    }
+}
+
+impl PartialEq<NaiveDate> for HolidayDate {
+    fn eq(&self, date: &NaiveDate) -> bool {
+        match self {
             HolidayDate::FixedDate(fixed) => fixed == date,
             HolidayDate::NthDate(nth) => nth == date,
+            _ => self.occurrences_in_year(date.year()).contains(date),
         }
     }
 }
@@ -21,6 +44,30 @@ impl<S: ToString> PartialEq<NthWeekdayOfMonth> for Holiday<S> {
     }
 }
 
+impl<S: ToString> PartialOrd<NthWeekdayOfMonth> for Holiday<S> {
+    /// Compare the Holiday's date rule with an NthWeekdayOfMonth, as `Ord for HolidayDate` does
+    fn partial_cmp(&self, nth: &NthWeekdayOfMonth) -> Option<Ordering> {
+        Some(self.date.cmp(&NthDate(*nth)))
+    }
+}
+
+impl<S: ToString> PartialEq<DayOfMonth> for Holiday<S> {
+    fn eq(&self, day_of_month: &DayOfMonth) -> bool {
+        if let HolidayDate::FixedDate(self_day_of_month) = self.date {
+            &self_day_of_month == day_of_month
+        } else {
+            false
+        }
+    }
+}
+
+impl<S: ToString> PartialOrd<DayOfMonth> for Holiday<S> {
+    /// Compare the Holiday's date rule with a DayOfMonth, as `Ord for HolidayDate` does
+    fn partial_cmp(&self, day_of_month: &DayOfMonth) -> Option<Ordering> {
+        Some(self.date.cmp(&FixedDate(*day_of_month)))
+    }
+}
+
 impl<S: ToString> PartialEq for Holiday<S> {
     fn eq(&self, other: &Self) -> bool {
         self.date == other.date && self.name.to_string() == other.name.to_string()
@@ -70,6 +117,100 @@ impl Ord for HolidayDate {
                     self_nwom.month.cmp(&other_dom.month)
                 }
             }
+            (
+                WeekdayRelative { weekday: self_weekday, anchor: self_anchor, count: self_count },
+                WeekdayRelative { weekday: other_weekday, anchor: other_anchor, count: other_count },
+            ) => self_anchor
+                .cmp(other_anchor)
+                .then(self_count.cmp(other_count))
+                .then(
+                    self_weekday
+                        .num_days_from_sunday()
+                        .cmp(&other_weekday.num_days_from_sunday()),
+                ),
+            (
+                Offset { base: self_base, days: self_days },
+                Offset { base: other_base, days: other_days },
+            ) => self_base.cmp(other_base).then(self_days.cmp(other_days)),
+            (FromYearEnd { offset_days: self_offset }, FromYearEnd { offset_days: other_offset }) => {
+                other_offset.cmp(self_offset)
+            }
+            (Hijri { month: self_month, day: self_day }, Hijri { month: other_month, day: other_day }) => {
+                self_month.cmp(other_month).then(self_day.cmp(other_day))
+            }
+            (
+                Substitute { base: self_base, avoid: self_avoid, shift: self_shift },
+                Substitute { base: other_base, avoid: other_avoid, shift: other_shift },
+            ) => self_base
+                .cmp(other_base)
+                .then(self_avoid.cmp(other_avoid))
+                .then(self_shift.cmp(other_shift)),
+            (
+                NthWeekdayAbsolute { count: self_count, weekday: self_weekday, from_month: self_month },
+                NthWeekdayAbsolute { count: other_count, weekday: other_weekday, from_month: other_month },
+            ) => self_month
+                .cmp(other_month)
+                .then(self_count.cmp(other_count))
+                .then(
+                    self_weekday
+                        .num_days_from_sunday()
+                        .cmp(&other_weekday.num_days_from_sunday()),
+                ),
+            (LastBusinessDay { month: self_month }, LastBusinessDay { month: other_month }) => {
+                self_month.cmp(other_month)
+            }
+            (DayOfYear { ordinal: self_ordinal }, DayOfYear { ordinal: other_ordinal }) => {
+                self_ordinal.cmp(other_ordinal)
+            }
+            (EasterRelative { days: self_days }, EasterRelative { days: other_days }) => self_days.cmp(other_days),
+            (
+                WeeklySeries { weekday: self_weekday, count: self_count, anchor: self_anchor, direction: self_direction },
+                WeeklySeries { weekday: other_weekday, count: other_count, anchor: other_anchor, direction: other_direction },
+            ) => self_anchor
+                .cmp(other_anchor)
+                .then(self_direction.cmp(other_direction))
+                .then(self_count.cmp(other_count))
+                .then(
+                    self_weekday
+                        .num_days_from_sunday()
+                        .cmp(&other_weekday.num_days_from_sunday()),
+                ),
+            (Table(self_entries), Table(other_entries)) => self_entries.cmp(other_entries),
+            (NearestWeekday { anchor: self_anchor }, NearestWeekday { anchor: other_anchor }) => {
+                self_anchor.cmp(other_anchor)
+            }
+            (
+                WeekdayAfter { base: self_base, weekday: self_weekday },
+                WeekdayAfter { base: other_base, weekday: other_weekday },
+            ) => self_base.cmp(other_base).then(
+                self_weekday
+                    .num_days_from_sunday()
+                    .cmp(&other_weekday.num_days_from_sunday()),
+            ),
+            _ => self.variant_order().cmp(&other.variant_order()),
+        }
+    }
+}
+
+impl HolidayDate {
+    /// Orders HolidayDates of different variants. Fixed and nth dates are interleaved by month,
+    /// so they share the lowest rank.
+    fn variant_order(&self) -> u8 {
+        match self {
+            FixedDate(_) | NthDate(_) => 0,
+            WeekdayRelative { .. } => 1,
+            Offset { .. } => 2,
+            FromYearEnd { .. } => 3,
+            Hijri { .. } => 4,
+            Substitute { .. } => 5,
+            NthWeekdayAbsolute { .. } => 6,
+            LastBusinessDay { .. } => 7,
+            DayOfYear { .. } => 8,
+            EasterRelative { .. } => 9,
+            WeeklySeries { .. } => 10,
+            Table(_) => 11,
+            NearestWeekday { .. } => 12,
+            WeekdayAfter { .. } => 13,
         }
     }
 }
@@ -99,40 +240,20 @@ impl PartialEq<NaiveDate> for DayOfMonth {
 
 impl PartialOrd for NthWeekdayOfMonth {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.month == other.month {
-            if self.nth == other.nth {
-                self.weekday
-                    .num_days_from_sunday()
-                    .partial_cmp(&other.weekday.num_days_from_sunday())
-            } else {
-                self.nth.partial_cmp(&other.nth)
-            }
-        } else {
-            self.month.partial_cmp(&other.month)
-        }
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for NthWeekdayOfMonth {
     fn cmp(&self, other: &Self) -> Ordering {
-        if self.month == other.month {
-            if self.nth == other.nth {
-                self.weekday
-                    .num_days_from_sunday()
-                    .cmp(&other.weekday.num_days_from_sunday())
-            } else {
-                self.nth.cmp(&other.nth)
-            }
-        } else {
-            self.month.cmp(&other.month)
-        }
+        self.cmp_with_week_start(other, WeekStart::default())
     }
 }
 
 impl PartialEq<NaiveDate> for NthWeekdayOfMonth {
     fn eq(&self, date: &NaiveDate) -> bool {
-        if self.nth == NthWeekday::Last && date.weekday() == self.weekday {
-            date.is_last_weekday()
+        if self.nth == NthWeekday::Last {
+            self.month == date.month() && is_last_weekday_of(date, self.weekday)
         } else {
             self == &NthWeekdayOfMonth::from(*date)
         }
@@ -172,3 +293,19 @@ impl PartialOrd<Month> for u32 {
         Some((*self).cmp(&(*m as u32)))
     }
 }
+
+#[test]
+fn holiday_cmp_date_rules() {
+    use holidays::{global::CHRISTMAS, united_states::THANKSGIVING};
+    // Fixed dates sort before nth weekdays in the same month
+    assert!(THANKSGIVING > DayOfMonth::new(11, November));
+    assert!(THANKSGIVING > DayOfMonth::new(30, November));
+    assert!(THANKSGIVING < DayOfMonth::new(1, December));
+    assert!(THANKSGIVING > NthWeekdayOfMonth::new(First, Weekday::Tue, November));
+    assert!(THANKSGIVING < NthWeekdayOfMonth::new(First, Weekday::Mon, December));
+    assert_eq!(THANKSGIVING.partial_cmp(&NthWeekdayOfMonth::new(Fourth, Weekday::Thu, November)), Some(Ordering::Equal));
+
+    assert_eq!(CHRISTMAS, DayOfMonth::new(25, December));
+    assert_eq!(CHRISTMAS.partial_cmp(&DayOfMonth::new(25, December)), Some(Ordering::Equal));
+    assert_ne!(THANKSGIVING, DayOfMonth::new(25, November));
+}
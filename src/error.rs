@@ -0,0 +1,26 @@
+//! Errors produced by the holiday crate
+
+use std::fmt;
+
+/// An error parsing or constructing a Holiday
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum HolidayError {
+    /// The input could not be parsed as a date description
+    InvalidInput(String),
+    /// The input is neither a named holiday nor a date description
+    UnknownHoliday(String),
+    /// The date rule has no occurrence in some years, e.g., February 29 or a 5th weekday
+    NotEveryYear(String),
+}
+
+impl fmt::Display for HolidayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HolidayError::InvalidInput(input) => write!(f, "Invalid date description: '{}'", input),
+            HolidayError::UnknownHoliday(input) => write!(f, "Unknown holiday: '{}'", input),
+            HolidayError::NotEveryYear(name) => write!(f, "'{}' does not occur every year", name),
+        }
+    }
+}
+
+impl std::error::Error for HolidayError {}
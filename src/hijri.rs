@@ -0,0 +1,68 @@
+//! # Hijri
+//!
+//! Conversion from the tabular (arithmetical) Islamic calendar to the Gregorian calendar.
+//!
+//! The tabular calendar uses a 30 year cycle of leap years rather than observations of the moon,
+//! so dates may differ by a day or two from the dates observed in a given country.
+
+use crate::*;
+
+use std::convert::TryFrom;
+
+/// The Julian day number of 1 Muharram, 1 AH (July 16, 622 in the Julian calendar)
+const EPOCH: i64 = 1_948_440;
+
+/// The Julian day number of January 1, 1 in the proleptic Gregorian calendar, minus one
+const CE_OFFSET: i64 = 1_721_425;
+
+/// The first Gregorian year with a date in the tabular Islamic calendar: 1 Muharram, 1 AH falls in
+/// 622
+pub const MIN_SUPPORTED_YEAR: i32 = 622;
+
+/// Convert a date in the tabular Islamic calendar to a Gregorian date
+pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    if year < 1 || !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+        return None;
+    }
+
+    let (year, month, day) = (year as i64, month as i64, day as i64);
+    let jdn = day
+        + (59 * (month - 1) + 1) / 2
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + EPOCH
+        - 1;
+
+    i32::try_from(jdn - CE_OFFSET)
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+}
+
+/// The tabular Islamic year a Gregorian date falls in
+pub fn year_of(date: &NaiveDate) -> i32 {
+    let jdn = date.num_days_from_ce() as i64 + CE_OFFSET;
+    (30 * (jdn - EPOCH) + 10646).div_euclid(10631) as i32
+}
+
+/// Every Gregorian date in a Gregorian year on which a tabular Islamic month and day fall.
+/// Because the Islamic year is about 11 days shorter than the Gregorian year, this may be zero,
+/// one, or two dates.
+pub fn occurrences_in_year(month: u32, day: u32, year: i32) -> Vec<NaiveDate> {
+    let (first, last) = match (NaiveDate::from_ymd_opt(year, 1, 1), NaiveDate::from_ymd_opt(year, 12, 31)) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Vec::new(),
+    };
+
+    (year_of(&first)..=year_of(&last))
+        .filter_map(|hijri_year| to_gregorian(hijri_year, month, day))
+        .filter(|date| date.year() == year)
+        .collect()
+}
+
+#[test]
+fn hijri_to_gregorian() {
+    assert_eq!(to_gregorian(1, 1, 1), Some(NaiveDate::from_ymd(622, 7, 19)));
+    assert_eq!(to_gregorian(1442, 9, 1), Some(NaiveDate::from_ymd(2021, 4, 13)));
+    assert_eq!(to_gregorian(1442, 13, 1), None);
+    assert_eq!(year_of(&NaiveDate::from_ymd(2021, 4, 13)), 1442);
+}
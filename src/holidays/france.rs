@@ -0,0 +1,67 @@
+//! Holidays in France, with French and English names
+
+use crate::*;
+
+holiday_const!(
+    /// New Year's Day (Jour de l'an): January 1
+    NEW_YEARS_DAY, "New Year's Day", January, 1,
+    names: &[("fr", "Jour de l'an"), ("en", "New Year's Day")]
+);
+holiday_const!(
+    /// Labour Day (Fête du Travail): May 1
+    LABOUR_DAY, "Labour Day", May, 1,
+    names: &[("fr", "Fête du Travail"), ("en", "Labour Day")]
+);
+holiday_const!(
+    /// Victory in Europe Day (Victoire 1945): May 8
+    VICTORY_IN_EUROPE_DAY, "Victory in Europe Day", May, 8,
+    names: &[("fr", "Victoire 1945"), ("en", "Victory in Europe Day")]
+);
+holiday_const!(
+    /// Bastille Day (Fête nationale): July 14
+    BASTILLE_DAY, "Bastille Day", July, 14,
+    names: &[("fr", "Fête nationale"), ("en", "Bastille Day")]
+);
+holiday_const!(
+    /// Assumption of Mary (Assomption): August 15
+    ASSUMPTION, "Assumption of Mary", August, 15,
+    names: &[("fr", "Assomption"), ("en", "Assumption of Mary")]
+);
+holiday_const!(
+    /// All Saints' Day (Toussaint): November 1
+    ALL_SAINTS_DAY, "All Saints' Day", November, 1,
+    names: &[("fr", "Toussaint"), ("en", "All Saints' Day")]
+);
+holiday_const!(
+    /// Armistice Day (Armistice 1918): November 11
+    ARMISTICE_DAY, "Armistice Day", November, 11,
+    names: &[("fr", "Armistice 1918"), ("en", "Armistice Day")]
+);
+holiday_const!(
+    /// Christmas (Noël): December 25
+    CHRISTMAS, "Christmas", December, 25,
+    names: &[("fr", "Noël"), ("en", "Christmas")]
+);
+
+/// All of the holidays in the France module
+pub const ALL: &[Holiday<&str>] = &[
+    NEW_YEARS_DAY,
+    LABOUR_DAY,
+    VICTORY_IN_EUROPE_DAY,
+    BASTILLE_DAY,
+    ASSUMPTION,
+    ALL_SAINTS_DAY,
+    ARMISTICE_DAY,
+    CHRISTMAS,
+];
+
+/// A `HolidayCalendar` of all of the holidays in the France module
+pub fn calendar() -> HolidayCalendar<&'static str> {
+    HolidayCalendar::from(ALL.to_vec())
+}
+
+#[test]
+fn localized_names() {
+    assert_eq!(BASTILLE_DAY.localized_name("fr"), "Fête nationale");
+    assert_eq!(BASTILLE_DAY.localized_name("en"), "Bastille Day");
+}
@@ -0,0 +1,44 @@
+//! Festivals that follow the full moon, looked up in tables of full moon dates
+
+use crate::*;
+
+/// The dates (UTC) of the full moon of May, from 2020 to 2030
+pub const MAY_FULL_MOON: &[(i32, u32, u32)] = &[
+    (2020, 5, 7),
+    (2021, 5, 26),
+    (2022, 5, 16),
+    (2023, 5, 5),
+    (2024, 5, 23),
+    (2025, 5, 12),
+    (2026, 5, 1),
+    (2027, 5, 20),
+    (2028, 5, 8),
+    (2029, 5, 27),
+    (2030, 5, 17),
+];
+
+holiday_const!(
+    /// Vesak: the full moon of May (2020 to 2030)
+    VESAK, "Vesak", date: HolidayDate::Table(MAY_FULL_MOON)
+);
+
+/// All of the holidays in the full moon module
+pub const ALL: &[Holiday<&str>] = &[VESAK];
+
+/// A `HolidayCalendar` of all of the holidays in the full moon module
+pub fn calendar() -> HolidayCalendar<&'static str> {
+    HolidayCalendar::from(ALL.to_vec())
+}
+
+#[test]
+fn vesak() {
+    assert_eq!(VESAK.in_year(2021), NaiveDate::from_ymd(2021, 5, 26));
+    assert_eq!(VESAK, NaiveDate::from_ymd(2021, 5, 26));
+    assert_eq!(VESAK.occurrences_in_year(2031), vec![]);
+    assert_eq!(VESAK.try_after(&NaiveDate::from_ymd(2030, 6, 1)), None);
+    assert_eq!(VESAK.try_before(&NaiveDate::from_ymd(2020, 5, 7)), None);
+    assert_eq!(VESAK.iter().count(), MAY_FULL_MOON.len());
+    assert_eq!(VESAK.iter().at(NaiveDate::from_ymd(2030, 1, 1)).collect::<Vec<_>>(), vec![NaiveDate::from_ymd(2030, 5, 17)]);
+    assert_eq!(VESAK.before(&NaiveDate::from_ymd(2022, 1, 1)), NaiveDate::from_ymd(2021, 5, 26));
+    assert!(VESAK.validate().is_err());
+}
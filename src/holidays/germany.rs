@@ -0,0 +1,63 @@
+//! Holidays in Germany, with German and English names
+
+use crate::*;
+
+holiday_const!(
+    /// New Year's Day (Neujahr): January 1
+    NEW_YEARS_DAY, "New Year's Day", January, 1,
+    names: &[("de", "Neujahr"), ("en", "New Year's Day")]
+);
+holiday_const!(
+    /// Labour Day (Tag der Arbeit): May 1
+    LABOUR_DAY, "Labour Day", May, 1,
+    names: &[("de", "Tag der Arbeit"), ("en", "Labour Day")]
+);
+holiday_const!(
+    /// German Unity Day (Tag der Deutschen Einheit): October 3
+    GERMAN_UNITY_DAY, "German Unity Day", October, 3,
+    names: &[("de", "Tag der Deutschen Einheit"), ("en", "German Unity Day")]
+);
+holiday_const!(
+    /// Christmas Eve (Heiligabend): December 24
+    CHRISTMAS_EVE, "Christmas Eve", December, 24,
+    names: &[("de", "Heiligabend"), ("en", "Christmas Eve")]
+);
+holiday_const!(
+    /// Christmas (Weihnachten): December 25
+    CHRISTMAS, "Christmas", December, 25,
+    names: &[("de", "Weihnachten"), ("en", "Christmas")]
+);
+holiday_const!(
+    /// St. Stephen's Day (Zweiter Weihnachtsfeiertag): December 26
+    ST_STEPHENS_DAY, "St. Stephen's Day", December, 26,
+    names: &[("de", "Zweiter Weihnachtsfeiertag"), ("en", "St. Stephen's Day")]
+);
+holiday_const!(
+    /// New Year's Eve (Silvester): December 31
+    NEW_YEARS_EVE, "New Year's Eve", December, 31,
+    names: &[("de", "Silvester"), ("en", "New Year's Eve")]
+);
+
+/// All of the holidays in the Germany module
+pub const ALL: &[Holiday<&str>] = &[
+    NEW_YEARS_DAY,
+    LABOUR_DAY,
+    GERMAN_UNITY_DAY,
+    CHRISTMAS_EVE,
+    CHRISTMAS,
+    ST_STEPHENS_DAY,
+    NEW_YEARS_EVE,
+];
+
+/// A `HolidayCalendar` of all of the holidays in the Germany module
+pub fn calendar() -> HolidayCalendar<&'static str> {
+    HolidayCalendar::from(ALL.to_vec())
+}
+
+#[test]
+fn localized_names() {
+    assert_eq!(CHRISTMAS.localized_name("de"), "Weihnachten");
+    assert_eq!(CHRISTMAS.localized_name("en"), "Christmas");
+    assert_eq!(CHRISTMAS.localized_name("fr"), "Christmas");
+    assert_eq!(super::global::CHRISTMAS.localized_name("de"), "Christmas");
+}
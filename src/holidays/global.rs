@@ -26,3 +26,152 @@ holiday_const!(
     /// Leap Day: February 29
     LEAP_DAY, "Leap Day", February, 29
 );
+holiday_const!(
+    /// Ash Wednesday: 46 days before Easter Sunday
+    ASH_WEDNESDAY, "Ash Wednesday", date: HolidayDate::EasterRelative { days: -46 }
+);
+holiday_const!(
+    /// Good Friday: 2 days before Easter Sunday
+    GOOD_FRIDAY, "Good Friday", date: HolidayDate::EasterRelative { days: -2 }
+);
+holiday_const!(
+    /// Easter Sunday
+    EASTER, "Easter", date: HolidayDate::EasterRelative { days: 0 }
+);
+holiday_const!(
+    /// Easter Monday: 1 day after Easter Sunday
+    EASTER_MONDAY, "Easter Monday", date: HolidayDate::EasterRelative { days: 1 }
+);
+holiday_const!(
+    /// Ascension Day: 39 days after Easter Sunday
+    ASCENSION_DAY, "Ascension Day", date: HolidayDate::EasterRelative { days: 39 }
+);
+holiday_const!(
+    /// Pentecost: 49 days after Easter Sunday
+    PENTECOST, "Pentecost", date: HolidayDate::EasterRelative { days: 49 }
+);
+holiday_const!(
+    /// First Sunday of Advent: 4th Sunday before December 25
+    ADVENT_1, "First Sunday of Advent", date: HolidayDate::WeekdayRelative {
+        weekday: Weekday::Sun,
+        anchor: DayOfMonth { day: 25, month: December },
+        count: -4,
+    }
+);
+holiday_const!(
+    /// Second Sunday of Advent: 3rd Sunday before December 25
+    ADVENT_2, "Second Sunday of Advent", date: HolidayDate::WeekdayRelative {
+        weekday: Weekday::Sun,
+        anchor: DayOfMonth { day: 25, month: December },
+        count: -3,
+    }
+);
+holiday_const!(
+    /// Third Sunday of Advent: 2nd Sunday before December 25
+    ADVENT_3, "Third Sunday of Advent", date: HolidayDate::WeekdayRelative {
+        weekday: Weekday::Sun,
+        anchor: DayOfMonth { day: 25, month: December },
+        count: -2,
+    }
+);
+holiday_const!(
+    /// Fourth Sunday of Advent: 1st Sunday before December 25
+    ADVENT_4, "Fourth Sunday of Advent", date: HolidayDate::WeekdayRelative {
+        weekday: Weekday::Sun,
+        anchor: DayOfMonth { day: 25, month: December },
+        count: -1,
+    }
+);
+holiday_const!(
+    /// Sundays of Advent: the 4 Sundays before December 25
+    ADVENT_SUNDAYS, "Sundays of Advent", date: HolidayDate::WeeklySeries {
+        weekday: Weekday::Sun,
+        count: 4,
+        anchor: DayOfMonth { day: 25, month: December },
+        direction: SeriesDirection::Before,
+    }
+);
+
+/// All of the holidays in the global module
+pub const ALL: &[Holiday<&str>] = &[
+    NEW_YEARS_DAY,
+    ST_PATRICKS_DAY,
+    ASH_WEDNESDAY,
+    GOOD_FRIDAY,
+    EASTER,
+    EASTER_MONDAY,
+    ASCENSION_DAY,
+    PENTECOST,
+    ADVENT_1,
+    ADVENT_2,
+    ADVENT_3,
+    ADVENT_4,
+    ADVENT_SUNDAYS,
+    CHRISTMAS_EVE,
+    CHRISTMAS,
+    NEW_YEARS_EVE,
+    LEAP_DAY,
+];
+
+/// A `HolidayCalendar` of all of the holidays in the global module
+pub fn calendar() -> HolidayCalendar<&'static str> {
+    HolidayCalendar::from(ALL.to_vec())
+}
+
+#[test]
+fn advent_sundays() {
+    assert_eq!(ADVENT_1.in_year(2021), NaiveDate::from_ymd(2021, 11, 28));
+    assert_eq!(ADVENT_4.in_year(2021), NaiveDate::from_ymd(2021, 12, 19));
+    assert_eq!(ADVENT_4.in_year(2022), NaiveDate::from_ymd(2022, 12, 18));
+    assert_eq!(ADVENT_1, NaiveDate::from_ymd(2022, 11, 27));
+    assert_ne!(ADVENT_1, NaiveDate::from_ymd(2022, 12, 4));
+}
+
+#[test]
+fn easter_relative() {
+    assert_eq!(EASTER.in_year(2021), NaiveDate::from_ymd(2021, 4, 4));
+    assert_eq!(GOOD_FRIDAY.in_year(2021), NaiveDate::from_ymd(2021, 4, 2));
+    assert_eq!(ASH_WEDNESDAY.in_year(2021), NaiveDate::from_ymd(2021, 2, 17));
+    assert_eq!(PENTECOST.in_year(2021), NaiveDate::from_ymd(2021, 5, 23));
+    assert_eq!(EASTER_MONDAY, NaiveDate::from_ymd(2024, 4, 1));
+}
+
+#[test]
+fn easter_relative_bounds() {
+    assert_eq!(EASTER.first_date(), EASTER.iter().next().unwrap());
+    assert_eq!(EASTER.last_date().year(), chrono::MAX_DATE.year());
+    assert_eq!(EASTER.iter().at(EASTER.last_date()).count(), 1);
+    assert_eq!(
+        EASTER.iter().at(NaiveDate::from_ymd(2021, 1, 1)).take(2).collect::<Vec<_>>(),
+        vec![NaiveDate::from_ymd(2021, 4, 4), NaiveDate::from_ymd(2022, 4, 17)]
+    );
+    assert_eq!(GOOD_FRIDAY.iter().at(NaiveDate::from_ymd(2021, 1, 1)).next(), Some(NaiveDate::from_ymd(2021, 4, 2)));
+    assert_eq!(EASTER.years_on_weekday(Weekday::Sun, 2020, 2022), vec![2020, 2021, 2022]);
+}
+
+#[test]
+fn advent_series() {
+    assert_eq!(ADVENT_SUNDAYS.occurrences_in_year(2021), vec![
+        NaiveDate::from_ymd(2021, 11, 28),
+        NaiveDate::from_ymd(2021, 12, 5),
+        NaiveDate::from_ymd(2021, 12, 12),
+        NaiveDate::from_ymd(2021, 12, 19),
+    ]);
+    assert_eq!(ADVENT_SUNDAYS.in_year(2021), ADVENT_1.in_year(2021));
+    assert_eq!(ADVENT_SUNDAYS.after(&NaiveDate::from_ymd(2021, 12, 6)), NaiveDate::from_ymd(2021, 12, 12));
+    assert_eq!(ADVENT_SUNDAYS.before(&NaiveDate::from_ymd(2021, 12, 6)), NaiveDate::from_ymd(2021, 12, 5));
+    assert_eq!(ADVENT_SUNDAYS, NaiveDate::from_ymd(2021, 12, 19));
+    assert_ne!(ADVENT_SUNDAYS, NaiveDate::from_ymd(2021, 12, 26));
+
+    let after = HolidayDate::WeeklySeries {
+        weekday: Weekday::Sun,
+        count: 2,
+        anchor: DayOfMonth::new(25, December),
+        direction: SeriesDirection::After,
+    };
+    // The series after December 25, 2020 ends in January 2021
+    assert_eq!(after.occurrences_in_year(2021), vec![
+        NaiveDate::from_ymd(2021, 1, 3),
+        NaiveDate::from_ymd(2021, 12, 26),
+    ]);
+}
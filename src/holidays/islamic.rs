@@ -0,0 +1,56 @@
+//! Islamic holidays, using the tabular Islamic calendar
+
+use crate::*;
+
+holiday_const!(
+    /// Islamic New Year: 1 Muharram
+    ISLAMIC_NEW_YEAR, "Islamic New Year", date: HolidayDate::Hijri { month: 1, day: 1 }
+);
+holiday_const!(
+    /// Ashura: 10 Muharram
+    ASHURA, "Ashura", date: HolidayDate::Hijri { month: 1, day: 10 }
+);
+holiday_const!(
+    /// Mawlid: 12 Rabi' al-Awwal
+    MAWLID, "Mawlid", date: HolidayDate::Hijri { month: 3, day: 12 }
+);
+holiday_const!(
+    /// First day of Ramadan: 1 Ramadan
+    RAMADAN, "Ramadan", date: HolidayDate::Hijri { month: 9, day: 1 }
+);
+holiday_const!(
+    /// Eid al-Fitr: 1 Shawwal
+    EID_AL_FITR, "Eid al-Fitr", date: HolidayDate::Hijri { month: 10, day: 1 }
+);
+holiday_const!(
+    /// Eid al-Adha: 10 Dhu al-Hijjah
+    EID_AL_ADHA, "Eid al-Adha", date: HolidayDate::Hijri { month: 12, day: 10 }
+);
+
+/// All of the holidays in the Islamic module
+pub const ALL: &[Holiday<&str>] = &[
+    ISLAMIC_NEW_YEAR,
+    ASHURA,
+    MAWLID,
+    RAMADAN,
+    EID_AL_FITR,
+    EID_AL_ADHA,
+];
+
+/// A `HolidayCalendar` of all of the holidays in the Islamic module
+pub fn calendar() -> HolidayCalendar<&'static str> {
+    HolidayCalendar::from(ALL.to_vec())
+}
+
+#[test]
+fn eid_twice_in_a_year() {
+    assert_eq!(EID_AL_FITR.occurrences_in_year(2000), vec![
+        NaiveDate::from_ymd(2000, 1, 8),
+        NaiveDate::from_ymd(2000, 12, 28),
+    ]);
+    assert_eq!(EID_AL_FITR.occurrences_in_year(2021), vec![NaiveDate::from_ymd(2021, 5, 13)]);
+    assert_eq!(EID_AL_FITR.in_year(2000), NaiveDate::from_ymd(2000, 1, 8));
+    assert_eq!(EID_AL_FITR, NaiveDate::from_ymd(2000, 12, 28));
+    assert_eq!(EID_AL_FITR.after(&NaiveDate::from_ymd(2000, 1, 9)), NaiveDate::from_ymd(2000, 12, 28));
+    assert_eq!(EID_AL_FITR.before(&NaiveDate::from_ymd(2000, 12, 28)), NaiveDate::from_ymd(2000, 1, 8));
+}
@@ -1,14 +1,31 @@
 //! A selection of pre-defined holidays provided for convenience
 
+pub mod france;
+pub mod full_moon;
+pub mod germany;
 pub mod global;
+pub mod islamic;
+pub mod united_kingdom;
 pub mod united_states;
 
-use crate::Holiday;
+use crate::{Holiday, HolidayCalendar, HolidayDate, HolidayError, NaiveDate};
 use std::str::FromStr;
 
 #[macro_export]
 /// Macro to create Holiday
 macro_rules! holiday {
+    ($name:expr, date: $date:expr) => {
+        Holiday {
+            name: $name,
+            date: $date,
+            observance: Observance::FullDay,
+            time: None,
+            valid_from: None,
+            recurrence: Recurrence::Annual,
+            names: &[],
+        }
+    };
+
     ($name:expr, $month:expr, $day:expr) => {
         Holiday {
             name: $name,
@@ -16,6 +33,11 @@ macro_rules! holiday {
                 day: $day,
                 month: $month,
             }),
+            observance: Observance::FullDay,
+            time: None,
+            valid_from: None,
+            recurrence: Recurrence::Annual,
+            names: &[],
         }
     };
 
@@ -27,58 +49,496 @@ macro_rules! holiday {
                 weekday: $weekday,
                 month: $month,
             }),
+            observance: Observance::FullDay,
+            time: None,
+            valid_from: None,
+            recurrence: Recurrence::Annual,
+            names: &[],
         }
     };
 }
 
+#[macro_export]
+/// Macro to create a fixed date Holiday, rejecting day numbers the month can never have at
+/// compile time. The month must be a `Month` constant.
+///
+/// ```
+/// use holiday::*;
+/// let pastover = holiday_checked!("Pastover", April, 2);
+/// assert_eq!(pastover.in_year(2021), NaiveDate::from_ymd(2021, 4, 2));
+/// ```
+///
+/// ```compile_fail
+/// use holiday::*;
+/// let invalid = holiday_checked!("Invalid", April, 31);
+/// ```
+macro_rules! holiday_checked {
+    ($name:expr, $month:expr, $day:expr) => {{
+        const _: () = assert!(
+            $day >= 1 && $day <= $crate::Month::max_days($month),
+            "invalid day of the month"
+        );
+        $crate::Holiday::new(
+            $name,
+            $crate::HolidayDate::FixedDate($crate::DayOfMonth { day: $day, month: $month }),
+        )
+    }};
+}
+
 #[macro_export]
 /// Macro to create a `pub const Holiday`
 macro_rules! holiday_const {
-    ($(#[$attr:meta])* $var:ident, $name:expr, $month:expr, $day:expr) => {
+    ($(#[$attr:meta])* $var:ident, $name:expr, date: $date:expr $(, names: $names:expr)?) => {
+        $(#[$attr])*
+        pub const $var: Holiday<&str> = holiday!($name, date: $date)$(.with_names($names))?;
+    };
+
+    ($(#[$attr:meta])* $var:ident, $name:expr, $month:expr, $day:expr $(, names: $names:expr)?) => {
         $(#[$attr])*
-        pub const $var: Holiday<&str> = holiday!($name, $month, $day);
+        pub const $var: Holiday<&str> = holiday_checked!($name, $month, $day)$(.with_names($names))?;
     };
 
-    ($(#[$attr:meta])* $var:ident, $name:expr, $nth:expr, $weekday:expr, $month:expr) => {
+    ($(#[$attr:meta])* $var:ident, $name:expr, $nth:expr, $weekday:expr, $month:expr $(, names: $names:expr)?) => {
         $(#[$attr])*
-        pub const $var: Holiday<&str> = holiday!($name, $nth, $weekday, $month);
+        pub const $var: Holiday<&str> = holiday!($name, $nth, $weekday, $month)$(.with_names($names))?;
     };
 }
 
+/// The built-in holiday modules and their holidays
+const REGIONS: &[(&str, &[Holiday<&str>])] = &[
+    ("global", global::ALL),
+    ("united_states", united_states::ALL),
+    ("united_kingdom", united_kingdom::ALL),
+    ("germany", germany::ALL),
+    ("france", france::ALL),
+    ("islamic", islamic::ALL),
+    ("full_moon", full_moon::ALL),
+];
+
+/// The names of the built-in holiday modules, in the same order as `REGIONS`
+const REGION_NAMES: &[&str] = &["global", "united_states", "united_kingdom", "germany", "france", "islamic", "full_moon"];
+
+/// Returns the names of the built-in holiday modules, e.g., "global" or "united_states"
+pub fn regions() -> &'static [&'static str] {
+    REGION_NAMES
+}
+
+/// Returns a calendar of the holidays in a built-in module by name (see `regions`)
+pub fn calendar_for(region: &str) -> Option<HolidayCalendar<&'static str>> {
+    REGIONS
+        .iter()
+        .find(|(name, _holidays)| *name == region)
+        .map(|(_name, holidays)| HolidayCalendar::from(holidays.to_vec()))
+}
+
+/// Returns every built-in holiday from every module. Holidays with the same name and date in
+/// multiple modules are only included once.
+pub fn all() -> Vec<Holiday<&'static str>> {
+    let mut all: Vec<Holiday<&str>> = Vec::new();
+    for holiday in REGIONS.iter().flat_map(|(_region, holidays)| holidays.iter()) {
+        if !all.contains(holiday) {
+            all.push(holiday.clone());
+        }
+    }
+    all
+}
+
+/// Returns every built-in holiday (see `all`) that occurs on a given date
+pub fn matching(date: &NaiveDate) -> Vec<Holiday<&'static str>> {
+    all().into_iter().filter(|holiday| holiday == date).collect()
+}
+
+/// Define `BuiltinHoliday` and `ALIASES` from a list of variants, holidays, and aliases
+macro_rules! builtin_holidays {
+    ($($(#[$attr:meta])* $variant:ident => $holiday:path: [$($alias:expr),+ $(,)?]),+ $(,)?) => {
+        /// The built-in holidays that can be found by name, e.g., with `"Thanksgiving".parse()`
+        #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+        pub enum BuiltinHoliday {
+            $($(#[$attr])* $variant,)+
+        }
+
+        impl BuiltinHoliday {
+            /// Returns every BuiltinHoliday
+            pub fn all() -> &'static [BuiltinHoliday] {
+                &[$(BuiltinHoliday::$variant,)+]
+            }
+
+            /// Returns the built-in Holiday
+            pub fn holiday(&self) -> Holiday<&'static str> {
+                match self {
+                    $(BuiltinHoliday::$variant => $holiday,)+
+                }
+            }
+
+            /// Returns the aliases of the built-in Holiday, in their normalized form (see
+            /// `normalize_name`)
+            pub fn aliases(&self) -> &'static [&'static str] {
+                match self {
+                    $(BuiltinHoliday::$variant => &[$($alias,)+],)+
+                }
+            }
+        }
+
+        /// Aliases for the built-in holidays, in their normalized form (see `normalize_name`)
+        pub const ALIASES: &[(&str, Holiday<&str>)] = &[$($(($alias, $holiday),)+)+];
+    };
+}
+
+builtin_holidays! {
+    /// Martin Luther King Jr. Day
+    MlkjDay => united_states::MLKJ_DAY: ["martin luther king jr", "mlkj"],
+    /// Groundhog Day
+    GroundhogDay => united_states::GROUNDHOG_DAY: ["groundhog"],
+    /// Super Bowl Sunday
+    SuperbowlSunday => united_states::SUPERBOWL_SUNDAY: ["superbowl sunday", "superbowl"],
+    /// President's Day
+    PresidentsDay => united_states::PRESIDENTS_DAY: ["presidents"],
+    /// Valentine's Day
+    ValentinesDay => united_states::VALENTINES_DAY: ["valentines"],
+    /// Daylight Saving Time Starts
+    DstStart => united_states::DST_START: ["daylight saving time starts"],
+    /// April Fool's Day
+    AprilFoolsDay => united_states::APRIL_FOOLS_DAY: ["april fools"],
+    /// Kentucky Derby
+    KentuckyDerby => united_states::KENTUCKY_DERBY: ["kentucky derby"],
+    /// Memorial Day
+    MemorialDay => united_states::MEMORIAL_DAY: ["memorial"],
+    /// Mother's Day
+    MothersDay => united_states::MOTHERS_DAY: ["mothers"],
+    /// Flag Day
+    FlagDay => united_states::FLAG_DAY: ["flag"],
+    /// Independence Day
+    IndependenceDay => united_states::INDEPENDENCE_DAY: ["independence", "july 4th", "july fourth", "fourth of july"],
+    /// Father's Day
+    FathersDay => united_states::FATHERS_DAY: ["fathers"],
+    /// Labor Day
+    LaborDay => united_states::LABOR_DAY: ["labor"],
+    /// Halloween
+    Halloween => united_states::HALLOWEEN: ["halloween"],
+    /// Columbus Day
+    ColumbusDay => united_states::COLUMBUS_DAY: ["columbus"],
+    /// Veteran's Day
+    VeteransDay => united_states::VETERANS_DAY: ["veterans"],
+    /// Daylight Saving Time Ends
+    DstEnd => united_states::DST_END: ["daylight saving time ends"],
+    /// Thanksgiving
+    Thanksgiving => united_states::THANKSGIVING: ["thanksgiving"],
+    /// Easter
+    Easter => global::EASTER: ["easter sunday", "easter"],
+    /// Good Friday
+    GoodFriday => global::GOOD_FRIDAY: ["good friday"],
+    /// Christmas Eve
+    ChristmasEve => global::CHRISTMAS_EVE: ["christmas eve"],
+    /// Christmas
+    Christmas => global::CHRISTMAS: ["christmas"],
+    /// Boxing Day
+    BoxingDay => united_kingdom::BOXING_DAY: ["boxing"],
+}
+
+impl FromStr for BuiltinHoliday {
+    type Err = HolidayError;
+    /// Find a BuiltinHoliday by one of its aliases
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let alias = normalize_name(s);
+        BuiltinHoliday::all()
+            .iter()
+            .find(|builtin| builtin.aliases().contains(&alias.as_str()))
+            .copied()
+            .ok_or_else(|| HolidayError::UnknownHoliday(s.into()))
+    }
+}
+
+/// Stable keys for the built-in holidays, e.g., "us.thanksgiving", suitable for storage. A slug is
+/// never changed or reused once published; new holidays only add new slugs.
+pub const SLUGS: &[(&str, Holiday<&str>)] = &[
+    ("global.new_years_day", global::NEW_YEARS_DAY),
+    ("global.st_patricks_day", global::ST_PATRICKS_DAY),
+    ("global.ash_wednesday", global::ASH_WEDNESDAY),
+    ("global.good_friday", global::GOOD_FRIDAY),
+    ("global.easter", global::EASTER),
+    ("global.easter_monday", global::EASTER_MONDAY),
+    ("global.ascension_day", global::ASCENSION_DAY),
+    ("global.pentecost", global::PENTECOST),
+    ("global.advent_1", global::ADVENT_1),
+    ("global.advent_2", global::ADVENT_2),
+    ("global.advent_3", global::ADVENT_3),
+    ("global.advent_4", global::ADVENT_4),
+    ("global.advent_sundays", global::ADVENT_SUNDAYS),
+    ("global.christmas_eve", global::CHRISTMAS_EVE),
+    ("global.christmas", global::CHRISTMAS),
+    ("global.new_years_eve", global::NEW_YEARS_EVE),
+    ("global.leap_day", global::LEAP_DAY),
+    ("us.mlkj_day", united_states::MLKJ_DAY),
+    ("us.groundhog_day", united_states::GROUNDHOG_DAY),
+    ("us.superbowl_sunday", united_states::SUPERBOWL_SUNDAY),
+    ("us.presidents_day", united_states::PRESIDENTS_DAY),
+    ("us.valentines_day", united_states::VALENTINES_DAY),
+    ("us.dst_start", united_states::DST_START),
+    ("us.april_fools_day", united_states::APRIL_FOOLS_DAY),
+    ("us.kentucky_derby", united_states::KENTUCKY_DERBY),
+    ("us.memorial_day", united_states::MEMORIAL_DAY),
+    ("us.mothers_day", united_states::MOTHERS_DAY),
+    ("us.flag_day", united_states::FLAG_DAY),
+    ("us.independence_day", united_states::INDEPENDENCE_DAY),
+    ("us.fathers_day", united_states::FATHERS_DAY),
+    ("us.labor_day", united_states::LABOR_DAY),
+    ("us.halloween", united_states::HALLOWEEN),
+    ("us.columbus_day", united_states::COLUMBUS_DAY),
+    ("us.veterans_day", united_states::VETERANS_DAY),
+    ("us.dst_end", united_states::DST_END),
+    ("us.thanksgiving", united_states::THANKSGIVING),
+    ("us.kwanzaa", united_states::KWANZAA),
+    ("uk.burns_night", united_kingdom::BURNS_NIGHT),
+    ("uk.st_davids_day", united_kingdom::ST_DAVIDS_DAY),
+    ("uk.st_georges_day", united_kingdom::ST_GEORGES_DAY),
+    ("uk.early_may_bank_holiday", united_kingdom::EARLY_MAY_BANK_HOLIDAY),
+    ("uk.spring_bank_holiday", united_kingdom::SPRING_BANK_HOLIDAY),
+    ("uk.summer_bank_holiday", united_kingdom::SUMMER_BANK_HOLIDAY),
+    ("uk.guy_fawkes_night", united_kingdom::GUY_FAWKES_NIGHT),
+    ("uk.remembrance_sunday", united_kingdom::REMEMBRANCE_SUNDAY),
+    ("uk.st_andrews_day", united_kingdom::ST_ANDREWS_DAY),
+    ("uk.boxing_day", united_kingdom::BOXING_DAY),
+    ("de.new_years_day", germany::NEW_YEARS_DAY),
+    ("de.labour_day", germany::LABOUR_DAY),
+    ("de.german_unity_day", germany::GERMAN_UNITY_DAY),
+    ("de.christmas_eve", germany::CHRISTMAS_EVE),
+    ("de.christmas", germany::CHRISTMAS),
+    ("de.st_stephens_day", germany::ST_STEPHENS_DAY),
+    ("de.new_years_eve", germany::NEW_YEARS_EVE),
+    ("fr.new_years_day", france::NEW_YEARS_DAY),
+    ("fr.labour_day", france::LABOUR_DAY),
+    ("fr.victory_in_europe_day", france::VICTORY_IN_EUROPE_DAY),
+    ("fr.bastille_day", france::BASTILLE_DAY),
+    ("fr.assumption", france::ASSUMPTION),
+    ("fr.all_saints_day", france::ALL_SAINTS_DAY),
+    ("fr.armistice_day", france::ARMISTICE_DAY),
+    ("fr.christmas", france::CHRISTMAS),
+    ("islamic.islamic_new_year", islamic::ISLAMIC_NEW_YEAR),
+    ("islamic.ashura", islamic::ASHURA),
+    ("islamic.mawlid", islamic::MAWLID),
+    ("islamic.ramadan", islamic::RAMADAN),
+    ("islamic.eid_al_fitr", islamic::EID_AL_FITR),
+    ("islamic.eid_al_adha", islamic::EID_AL_ADHA),
+    ("full_moon.vesak", full_moon::VESAK),
+];
+
+/// Returns the date rules shared by more than one holiday in the built-in modules, with the
+/// distinct names of the holidays sharing each rule, including their localized names, e.g.,
+/// December 25 with "Christmas", "Weihnachten", and "Noël"
+pub fn duplicate_rules() -> Vec<(HolidayDate, Vec<&'static str>)> {
+    let mut rules: Vec<(HolidayDate, usize, Vec<&'static str>)> = Vec::new();
+    for holiday in REGIONS.iter().flat_map(|(_region, holidays)| holidays.iter()) {
+        let index = match rules.iter().position(|(date, _count, _names)| *date == holiday.date) {
+            Some(index) => index,
+            None => {
+                rules.push((holiday.date.clone(), 0, Vec::new()));
+                rules.len() - 1
+            }
+        };
+        let (_date, count, names) = &mut rules[index];
+        *count += 1;
+        let localized = holiday.names.iter().map(|(_lang, name)| *name);
+        for name in std::iter::once(holiday.name).chain(localized) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    rules
+        .into_iter()
+        .filter(|(_date, count, _names)| *count > 1)
+        .map(|(date, _count, names)| (date, names))
+        .collect()
+}
+
+/// Normalize a holiday name for alias lookup: lowercase, without apostrophes or periods, and
+/// without a leading "the" or a trailing "day"
+pub fn normalize_name(name: &str) -> String {
+    let name = name.to_lowercase().replace(&['\'', '.'][..], "");
+    let name = name.trim();
+    let name = name.strip_prefix("the ").unwrap_or(name);
+    let name = name.strip_suffix(" day").unwrap_or(name);
+    name.trim().to_string()
+}
+
+impl Holiday<&'static str> {
+    /// Find a built-in Holiday by its stable slug, e.g., "us.thanksgiving"
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        SLUGS
+            .iter()
+            .find(|(key, _holiday)| *key == slug)
+            .map(|(_key, holiday)| holiday.clone())
+    }
+
+    /// Find a built-in Holiday by one of its aliases
+    pub fn from_alias(alias: &str) -> Option<Self> {
+        alias.parse::<BuiltinHoliday>().ok().map(|builtin| builtin.holiday())
+    }
+}
+
+impl<S: ToString> Holiday<S> {
+    /// The stable slug of a built-in Holiday with the same name and date rule. Holidays shared
+    /// by multiple modules, like Christmas, return the first matching slug.
+    pub fn slug(&self) -> Option<&'static str> {
+        SLUGS
+            .iter()
+            .find(|(_key, holiday)| self.name.to_string() == holiday.name && holiday.same_date(self))
+            .map(|(key, _holiday)| *key)
+    }
+}
+
 impl FromStr for Holiday<&str> {
-    type Err = std::io::Error;
+    type Err = HolidayError;
+    /// Find a built-in Holiday by one of its aliases
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use united_states::*;
-        use global::*;
-        Ok(match s
-            .to_lowercase()
-            .replace("\'", "")
-            .trim_start_matches("the")
-            .trim_end_matches(" day")
-            .trim()
+        Holiday::from_alias(s).ok_or_else(|| HolidayError::UnknownHoliday(s.into()))
+    }
+}
+
+impl FromStr for Holiday<String> {
+    type Err = HolidayError;
+    /// Parse a named holiday, a date description like "4th Thursday in November" or
+    /// "December 25" named after the input, or a name followed by a date description in
+    /// parentheses, as formatted by `Display`: "Thanksgiving (4th Thursday in November)".
+    /// Returns `HolidayError::UnknownHoliday` if the input does not look like a date
+    /// description, or `HolidayError::InvalidInput` if it is a malformed one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((name, description)) = s
+            .strip_suffix(')')
+            .and_then(|s| s.rfind(" (").map(|i| (&s[..i], &s[i + 2..])))
         {
-            "martin luther king jr" | "mlkj" => MLKJ_DAY,
-            "groundhog" => GROUNDHOG_DAY,
-            "superbowl sunday" | "superbowl" => SUPERBOWL_SUNDAY,
-            "presidents" => PRESIDENTS_DAY,
-            "valentines" => VALENTINES_DAY,
-            "daylight saving time starts" => DST_START,
-            "april fools" => APRIL_FOOLS_DAY,
-            "kentucky derby" => KENTUCKY_DERBY,
-            "memorial" => MEMORIAL_DAY,
-            "mothers" => MOTHERS_DAY,
-            "flag" => FLAG_DAY,
-            "independence" | "july 4th" | "july fourth" | "fourth of july" => INDEPENDENCE_DAY,
-            "fathers" => FATHERS_DAY,
-            "labor" => LABOR_DAY,
-            "halloween" => HALLOWEEN,
-            "columbus" => COLUMBUS_DAY,
-            "veterans" => VETERANS_DAY,
-            "daylight saving time ends" => DST_END,
-            "thanksgiving" => THANKSGIVING,
-            "christmas eve" => CHRISTMAS_EVE,
-            "christmas" => CHRISTMAS,
-            _ => return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput))
-        })
+            return Ok(Holiday::new(name.to_string(), description.parse::<HolidayDate>()?));
+        }
+
+        match s.parse::<Holiday<&str>>() {
+            Ok(holiday) => Ok(holiday.with_name(holiday.name.to_string())),
+            Err(unknown) => match s.parse::<HolidayDate>() {
+                Ok(date) => Ok(Holiday::new(s.to_string(), date)),
+                Err(invalid) if crate::parse::looks_like_description(s) => Err(invalid),
+                Err(_) => Err(unknown),
+            },
+        }
     }
 }
+
+#[test]
+fn parse_owned() {
+    let holiday = "4th Thursday in November".parse::<Holiday<String>>().unwrap();
+    assert_eq!(holiday.name(), "4th Thursday in November");
+    assert_eq!(holiday.in_year(2021), crate::NaiveDate::from_ymd(2021, 11, 25));
+
+    let holiday = "thanksgiving".parse::<Holiday<String>>().unwrap();
+    assert_eq!(holiday.name(), "Thanksgiving");
+
+    assert!("asdf".parse::<Holiday<String>>().is_err());
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!("christmas".parse::<Holiday<String>>().map(|holiday| holiday.name), Ok("Christmas".to_string()));
+    assert!("4th Thursday in November".parse::<Holiday<String>>().is_ok());
+    assert_eq!(
+        "asdf".parse::<Holiday<String>>().map(|holiday| holiday.name),
+        Err(HolidayError::UnknownHoliday("asdf".into()))
+    );
+    assert_eq!(
+        "December 32".parse::<Holiday<String>>().map(|holiday| holiday.name),
+        Err(HolidayError::InvalidInput("December 32".into()))
+    );
+    assert_eq!("asdf".parse::<Holiday<&str>>(), Err(HolidayError::UnknownHoliday("asdf".into())));
+}
+
+#[test]
+fn normalize_names() {
+    assert_eq!(normalize_name("The Fourth of July"), "fourth of july");
+    assert_eq!(normalize_name("  Martin Luther King Jr. Day "), "martin luther king jr");
+    assert_eq!(normalize_name("Veteran's Day"), "veterans");
+    assert_eq!(normalize_name("Thanksgiving"), "thanksgiving");
+}
+
+#[test]
+fn from_alias() {
+    use united_states::INDEPENDENCE_DAY;
+    assert_eq!(Holiday::from_alias("the fourth of july"), Some(INDEPENDENCE_DAY));
+    assert_eq!(Holiday::from_alias("July 4th"), Some(INDEPENDENCE_DAY));
+    assert_eq!(Holiday::from_alias("independence"), Some(INDEPENDENCE_DAY));
+    assert_eq!(Holiday::from_alias("Independence Day"), Some(INDEPENDENCE_DAY));
+    assert_eq!(Holiday::from_alias("asdf"), None);
+}
+
+#[test]
+fn regions_by_name() {
+    let names = REGIONS.iter().map(|(name, _holidays)| *name).collect::<Vec<_>>();
+    assert_eq!(regions(), names.as_slice());
+
+    let calendar = calendar_for("united_states").unwrap();
+    assert!(calendar.holidays().contains(&united_states::THANKSGIVING));
+    assert!(calendar_for("atlantis").is_none());
+}
+
+#[test]
+fn builtin_holidays() {
+    for builtin in BuiltinHoliday::all() {
+        assert!(
+            builtin.aliases().iter().any(|alias| alias.parse::<BuiltinHoliday>() == Ok(*builtin)),
+            "{:?} does not parse from any alias",
+            builtin
+        );
+        assert!(builtin.holiday().slug().is_some());
+    }
+
+    assert_eq!("Thanksgiving Day".parse::<BuiltinHoliday>(), Ok(BuiltinHoliday::Thanksgiving));
+    assert_eq!(BuiltinHoliday::Christmas.holiday(), global::CHRISTMAS);
+    assert_eq!(ALIASES.len(), BuiltinHoliday::all().iter().map(|builtin| builtin.aliases().len()).sum::<usize>());
+}
+
+#[test]
+fn all_dedupe() {
+    let all = all();
+    assert_eq!(all.iter().filter(|holiday| **holiday == global::CHRISTMAS).count(), 1);
+    assert!(all.contains(&united_states::THANKSGIVING));
+    assert!(all.contains(&france::BASTILLE_DAY));
+}
+
+#[test]
+fn slugs() {
+    use united_states::THANKSGIVING;
+    assert_eq!(THANKSGIVING.slug(), Some("us.thanksgiving"));
+    assert_eq!(Holiday::from_slug("us.thanksgiving"), Some(THANKSGIVING));
+    assert_eq!(global::CHRISTMAS.slug(), Some("global.christmas"));
+    assert_eq!(Holiday::new_fixed("Pastover", 4, 2).slug(), None);
+    assert_eq!(Holiday::from_slug("us.asdf"), None);
+
+    for holiday in all() {
+        assert!(holiday.slug().is_some(), "no slug for {}", holiday.name());
+    }
+    for (i, (slug, _holiday)) in SLUGS.iter().enumerate() {
+        assert!(SLUGS[..i].iter().all(|(other, _holiday)| other != slug), "duplicate slug {}", slug);
+    }
+}
+
+#[test]
+fn matching_date() {
+    assert_eq!(matching(&NaiveDate::from_ymd(2020, 12, 25)), vec![global::CHRISTMAS]);
+    assert_eq!(matching(&NaiveDate::from_ymd(2021, 11, 25)), vec![united_states::THANKSGIVING]);
+    assert!(matching(&NaiveDate::from_ymd(2021, 6, 2)).is_empty());
+}
+
+#[test]
+fn duplicate_christmas_rules() {
+    let rules = duplicate_rules();
+    let names = |rule: &HolidayDate| {
+        rules
+            .iter()
+            .find(|(date, _names)| date == rule)
+            .map(|(_date, names)| names.clone())
+    };
+    assert_eq!(names(&global::CHRISTMAS.date), Some(vec!["Christmas", "Weihnachten", "Noël"]));
+    assert_eq!(
+        names(&united_kingdom::BOXING_DAY.date),
+        Some(vec!["Kwanzaa", "Boxing Day", "St. Stephen's Day", "Zweiter Weihnachtsfeiertag"])
+    );
+    assert_eq!(names(&united_states::THANKSGIVING.date), None);
+    assert_eq!(names(&germany::GERMAN_UNITY_DAY.date), None);
+}
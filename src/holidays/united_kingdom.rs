@@ -0,0 +1,99 @@
+//! Holidays in the United Kingdom
+
+use crate::*;
+use NthWeekday::*;
+
+holiday_const!(
+    /// Burns Night: January 25
+    BURNS_NIGHT, "Burns Night", January, 25
+);
+holiday_const!(
+    /// St. David's Day: March 1
+    ST_DAVIDS_DAY, "St. David's Day", March, 1
+);
+holiday_const!(
+    /// St. George's Day: April 23
+    ST_GEORGES_DAY, "St. George's Day", April, 23
+);
+holiday_const!(
+    /// Early May Bank Holiday: 1st Monday in May
+    EARLY_MAY_BANK_HOLIDAY, "Early May Bank Holiday", First, Weekday::Mon, May
+);
+holiday_const!(
+    /// Spring Bank Holiday: Last Monday in May
+    SPRING_BANK_HOLIDAY, "Spring Bank Holiday", Last, Weekday::Mon, May
+);
+holiday_const!(
+    /// Summer Bank Holiday: Last Monday in August
+    SUMMER_BANK_HOLIDAY, "Summer Bank Holiday", Last, Weekday::Mon, August
+);
+holiday_const!(
+    /// Guy Fawkes Night: November 5
+    GUY_FAWKES_NIGHT, "Guy Fawkes Night", November, 5
+);
+holiday_const!(
+    /// Remembrance Sunday: 2nd Sunday in November
+    REMEMBRANCE_SUNDAY, "Remembrance Sunday", Second, Weekday::Sun, November
+);
+holiday_const!(
+    /// St. Andrew's Day: November 30
+    ST_ANDREWS_DAY, "St. Andrew's Day", November, 30
+);
+holiday_const!(
+    /// Boxing Day: December 26
+    BOXING_DAY, "Boxing Day", December, 26
+);
+
+/// All of the holidays in the United Kingdom module
+pub const ALL: &[Holiday<&str>] = &[
+    BURNS_NIGHT,
+    ST_DAVIDS_DAY,
+    ST_GEORGES_DAY,
+    EARLY_MAY_BANK_HOLIDAY,
+    SPRING_BANK_HOLIDAY,
+    SUMMER_BANK_HOLIDAY,
+    GUY_FAWKES_NIGHT,
+    REMEMBRANCE_SUNDAY,
+    ST_ANDREWS_DAY,
+    BOXING_DAY,
+];
+
+/// A `HolidayCalendar` of all of the holidays in the United Kingdom module
+pub fn calendar() -> HolidayCalendar<&'static str> {
+    HolidayCalendar::from(ALL.to_vec())
+}
+
+#[test]
+fn bank_holidays() {
+    assert_eq!(EARLY_MAY_BANK_HOLIDAY.in_year(2021), NaiveDate::from_ymd(2021, 5, 3));
+    assert_eq!(SPRING_BANK_HOLIDAY.in_year(2021), NaiveDate::from_ymd(2021, 5, 31));
+    assert_eq!(SUMMER_BANK_HOLIDAY.in_year(2021), NaiveDate::from_ymd(2021, 8, 30));
+}
+
+#[test]
+fn boxing_day_substitute() {
+    let christmas = HolidayDate::Substitute {
+        base: Box::new(holidays::global::CHRISTMAS.date),
+        avoid: vec![],
+        shift: WeekendShift::NextMonday,
+    };
+    let boxing_day = HolidayDate::Substitute {
+        base: Box::new(BOXING_DAY.date),
+        avoid: vec![christmas.clone()],
+        shift: WeekendShift::NextMonday,
+    };
+
+    // Christmas on a Saturday, Boxing Day on a Sunday
+    assert_eq!(christmas.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 12, 27));
+    assert_eq!(boxing_day.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 12, 28));
+
+    // Christmas on a Friday, Boxing Day on a Saturday
+    assert_eq!(boxing_day.after(&NaiveDate::from_ymd(2020, 1, 1)), NaiveDate::from_ymd(2020, 12, 28));
+
+    // Christmas on a Sunday, Boxing Day on a Monday
+    assert_eq!(christmas.after(&NaiveDate::from_ymd(2022, 1, 1)), NaiveDate::from_ymd(2022, 12, 26));
+    assert_eq!(boxing_day.after(&NaiveDate::from_ymd(2022, 1, 1)), NaiveDate::from_ymd(2022, 12, 27));
+
+    // No substitute needed
+    assert_eq!(boxing_day.after(&NaiveDate::from_ymd(2019, 1, 1)), NaiveDate::from_ymd(2019, 12, 26));
+}
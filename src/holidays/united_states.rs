@@ -79,6 +79,37 @@ holiday_const!(
     /// Thanksgiving: 4th Thursday in November
     THANKSGIVING, "Thanksgiving", Fourth, Weekday::Thu, November
 );
+/// Kwanzaa: December 26, the first day of the week-long celebration, first held in 1966
+pub const KWANZAA: Holiday<&str> = holiday_checked!("Kwanzaa", December, 26).with_valid_from(1966);
+
+/// All of the holidays in the United States module
+pub const ALL: &[Holiday<&str>] = &[
+    MLKJ_DAY,
+    GROUNDHOG_DAY,
+    SUPERBOWL_SUNDAY,
+    PRESIDENTS_DAY,
+    VALENTINES_DAY,
+    DST_START,
+    APRIL_FOOLS_DAY,
+    KENTUCKY_DERBY,
+    MEMORIAL_DAY,
+    MOTHERS_DAY,
+    FLAG_DAY,
+    INDEPENDENCE_DAY,
+    FATHERS_DAY,
+    LABOR_DAY,
+    HALLOWEEN,
+    COLUMBUS_DAY,
+    VETERANS_DAY,
+    DST_END,
+    THANKSGIVING,
+    KWANZAA,
+];
+
+/// A `HolidayCalendar` of all of the holidays in the United States module
+pub fn calendar() -> HolidayCalendar<&'static str> {
+    HolidayCalendar::from(ALL.to_vec())
+}
 
 #[test]
 fn holiday_eq() {
@@ -113,3 +144,28 @@ fn holiday_ord() {
 
     assert_eq!(vec, exp);
 }
+
+#[test]
+fn calendar_is_holiday() {
+    let calendar = calendar();
+    assert_eq!(calendar.is_holiday(&NaiveDate::from_ymd(2020, 11, 26)), Some(&THANKSGIVING));
+    assert_eq!(calendar.is_holiday(&NaiveDate::from_ymd(2020, 11, 27)), None);
+}
+
+#[test]
+fn kwanzaa_valid_from() {
+    assert_eq!(KWANZAA.iter().next(), Some(NaiveDate::from_ymd(1966, 12, 26)));
+    assert_eq!(KWANZAA.first_date(), NaiveDate::from_ymd(1966, 12, 26));
+    assert_eq!(KWANZAA.in_year(1900), NaiveDate::from_ymd(1966, 12, 26));
+    assert_eq!(KWANZAA.before(&NaiveDate::from_ymd(1967, 1, 1)), NaiveDate::from_ymd(1966, 12, 26));
+    assert_eq!(KWANZAA.try_before(&NaiveDate::from_ymd(1966, 12, 26)), None);
+    assert_eq!(
+        KWANZAA.iter().at(NaiveDate::from_ymd(1968, 1, 1)).rev().collect::<Vec<_>>(),
+        vec![NaiveDate::from_ymd(1967, 12, 26), NaiveDate::from_ymd(1966, 12, 26)]
+    );
+    assert_eq!(KWANZAA.previous_n(NaiveDate::from_ymd(1968, 1, 1), 5).len(), 2);
+    assert!(KWANZAA.occurrences_in_year(1965).is_empty());
+    assert_ne!(KWANZAA, NaiveDate::from_ymd(1965, 12, 26));
+    assert_eq!(KWANZAA, NaiveDate::from_ymd(2021, 12, 26));
+    assert_eq!(KWANZAA.days_until_fast(NaiveDate::from_ymd(1966, 12, 1)), 25);
+}
@@ -0,0 +1,89 @@
+//! Conversion of holidays to `icalendar` events
+
+use crate::*;
+
+use icalendar::{Component, Event, EventLike, Property};
+
+impl<S: ToString> Holiday<S> {
+    /// Create an all-day `icalendar::Event` for the occurrences of the Holiday in a range of years
+    /// (inclusive). Fixed dates and nth weekdays repeat with an RRULE; other date rules, like
+    /// Easter-relative and lunar dates, list their occurrences with an RDATE. A Holiday with no
+    /// occurrences in the range gives an event with only a summary.
+    pub fn to_icalendar_event(&self, year_start: i32, year_end: i32) -> Event {
+        let mut event = Event::new();
+        event.summary(&self.name.to_string());
+
+        let dates = (year_start..=year_end)
+            .flat_map(|year| self.occurrences_in_year(year))
+            .collect::<Vec<_>>();
+        let first = match dates.first() {
+            Some(first) => *first,
+            None => return event.done(),
+        };
+        event.all_day(first);
+
+        match self.rrule() {
+            Some(rule) => {
+                event.add_property("RRULE", format!("{};UNTIL={}1231", rule, year_end));
+            },
+            None if dates.len() > 1 => {
+                let rdates = dates[1..]
+                    .iter()
+                    .map(|date| date.format("%Y%m%d").to_string())
+                    .collect::<Vec<_>>();
+                event.append_property(Property::new("RDATE", rdates.join(",")).add_parameter("VALUE", "DATE").done());
+            },
+            None => (),
+        }
+
+        event.done()
+    }
+
+    /// The RRULE of the Holiday without an end date, if its date rule can be expressed as one
+    fn rrule(&self) -> Option<String> {
+        let by_day = |nth: &NthWeekdayOfMonth| {
+            let n = match nth.nth {
+                NthWeekday::Last => -1,
+                n => n as i32,
+            };
+            format!("{}{}", n, format!("{:?}", nth.weekday)[..2].to_uppercase())
+        };
+
+        match (self.recurrence, &self.date) {
+            (Recurrence::Monthly, FixedDate(day_of_month)) => Some(format!("FREQ=MONTHLY;BYMONTHDAY={}", day_of_month.day)),
+            (Recurrence::Monthly, NthDate(nth)) => Some(format!("FREQ=MONTHLY;BYDAY={}", by_day(nth))),
+            (Recurrence::Monthly, _) => None,
+            (recurrence, date) => {
+                let rule = match date {
+                    FixedDate(day_of_month) => {
+                        format!("FREQ=YEARLY;BYMONTH={};BYMONTHDAY={}", day_of_month.month as u32, day_of_month.day)
+                    },
+                    NthDate(nth) => format!("FREQ=YEARLY;BYMONTH={};BYDAY={}", nth.month as u32, by_day(nth)),
+                    _ => return None,
+                };
+                match recurrence {
+                    Recurrence::EveryNYears(n) => Some(format!("{};INTERVAL={}", rule, n.max(1))),
+                    _ => Some(rule),
+                }
+            },
+        }
+    }
+}
+
+#[test]
+fn icalendar_event() {
+    use holidays::{global::EASTER, united_states::*};
+
+    let event = THANKSGIVING.to_icalendar_event(2021, 2030);
+    assert_eq!(event.get_summary(), Some("Thanksgiving"));
+    assert_eq!(event.property_value("RRULE"), Some("FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;UNTIL=20301231"));
+    assert_eq!(event.property_value("DTSTART"), Some("20211125"));
+
+    let event = MEMORIAL_DAY.to_icalendar_event(2021, 2021);
+    assert_eq!(event.property_value("RRULE"), Some("FREQ=YEARLY;BYMONTH=5;BYDAY=-1MO;UNTIL=20211231"));
+
+    let event = EASTER.to_icalendar_event(2021, 2022);
+    assert_eq!(event.get_summary(), Some("Easter"));
+    assert_eq!(event.property_value("RRULE"), None);
+    assert_eq!(event.property_value("RDATE"), Some("20220417"));
+}
@@ -3,7 +3,11 @@
 use crate::*;
 
 #[derive(Debug)]
-/// An iterator over the occurrences of a Holiday
+/// An iterator over the occurrences of a Holiday.
+///
+/// The iterator is a cursor between two inclusive bounds: `next` yields the occurrences after
+/// `current` and `next_back` the occurrences before it. The bounds and cursor always satisfy
+/// `first - 1 day <= current <= last`.
 pub struct HolidayIter<'h, H: BeforeAfterDate> {
     holiday: &'h H,
     first: NaiveDate,
@@ -12,30 +16,53 @@ pub struct HolidayIter<'h, H: BeforeAfterDate> {
 }
 
 impl<'h, H: BeforeAfterDate> HolidayIter<'h, H> {
-    /// Set the current date: the current position of the iterator
+    /// Creates an iterator over every representable occurrence of a holiday
+    fn new(holiday: &'h H) -> Self {
+        let first = holiday.try_after(&NaiveDate::MIN).unwrap_or(NaiveDate::MIN);
+        HolidayIter {
+            holiday,
+            first,
+            last: holiday.try_before(&NaiveDate::MAX).unwrap_or(first),
+            current: first.pred_opt().unwrap_or(first),
+        }
+    }
+
+    /// Set the current date: the current position of the iterator. The bounds are widened to
+    /// include the date if necessary.
     pub fn at(mut self, current_date: NaiveDate) -> Self {
-        self.current = current_date.pred();
+        self.current = current_date.pred_opt().unwrap_or(current_date);
         self.shift_from(current_date);
 
         self
     }
 
-    /// Start the iterator at the given date
+    /// Start the iterator at the given date (inclusive)
     pub fn starting_at(mut self, start_date: NaiveDate) -> Self {
-        self.first = self.holiday.after(&start_date);
-        self.shift_from(start_date);
+        self.first = start_date;
+        if self.last < start_date {
+            self.last = start_date;
+        }
+        self.clamp_current();
 
         self
     }
 
-    /// End the iterator at the given date
+    /// End the iterator at the given date (inclusive)
     pub fn ending_at(mut self, end_date: NaiveDate) -> Self {
-        self.last = self.holiday.before(&end_date);
-        self.shift_from(end_date);
+        self.last = end_date;
+        if self.first > end_date {
+            self.first = end_date;
+        }
+        self.clamp_current();
 
         self
     }
 
+    /// Only yield the occurrences that fall on a weekend
+    pub fn weekends_only(self) -> std::iter::Filter<Self, fn(&NaiveDate) -> bool> {
+        self.filter(|date| date.is_weekend())
+    }
+
     /// Set first and last dates for the iterator if a new date is not between the first
     /// and last dates.
     fn shift_from(&mut self, date: NaiveDate) {
@@ -47,12 +74,24 @@ impl<'h, H: BeforeAfterDate> HolidayIter<'h, H> {
             self.last = date;
         }
     }
+
+    /// Move the current date back between the first and last dates after the bounds change
+    fn clamp_current(&mut self) {
+        let before_first = self.first.pred_opt().unwrap_or(self.first);
+        if self.current < before_first {
+            self.current = before_first;
+        }
+
+        if self.current > self.last {
+            self.current = self.last;
+        }
+    }
 }
 
 impl<'h, H: BeforeAfterDate> Iterator for HolidayIter<'h, H> {
     type Item = NaiveDate;
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.holiday.after(&self.current.succ());
+        let next = self.holiday.try_after(&self.current.succ_opt()?)?;
         if next <= self.last {
             self.current = next;
             Some(next)
@@ -64,8 +103,8 @@ impl<'h, H: BeforeAfterDate> Iterator for HolidayIter<'h, H> {
 
 impl<'h, H: BeforeAfterDate> DoubleEndedIterator for HolidayIter<'h, H> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        let prev = self.holiday.before(&self.current);
-        if self.current >= self.first {
+        let prev = self.holiday.try_before(&self.current)?;
+        if prev >= self.first {
             self.current = prev;
             Some(prev)
         } else {
@@ -80,12 +119,7 @@ macro_rules! impl_holiday_into_iter {
             type Item = NaiveDate;
             type IntoIter = HolidayIter<'h, $ty>;
             fn into_iter(self) -> Self::IntoIter {
-                HolidayIter {
-                    holiday: &self,
-                    first: self.first_date(),
-                    last: self.last_date(),
-                    current: self.first_date(),
-                }
+                HolidayIter::new(self)
             }
         }
     };
@@ -95,12 +129,7 @@ macro_rules! impl_holiday_into_iter {
             type Item = NaiveDate;
             type IntoIter = HolidayIter<'h, $ty>;
             fn into_iter(self) -> Self::IntoIter {
-                HolidayIter {
-                    holiday: &self,
-                    first: self.first_date(),
-                    last: self.last_date(),
-                    current: self.first_date(),
-                }
+                HolidayIter::new(self)
             }
         }
     }
@@ -178,6 +207,106 @@ mod test {
         assert_eq!(dec_iter.next(), Some(NaiveDate::from_ymd(2025, 12, 31)));
     }
 
+    #[test]
+    fn schedule_between() {
+        let tgives = THANKSGIVING
+            .schedule_between(NaiveDate::from_ymd(2020, 1, 1), NaiveDate::from_ymd(2022, 12, 31))
+            .collect::<Vec<_>>();
+        assert_eq!(tgives, vec![
+            NaiveDate::from_ymd(2020, 11, 26),
+            NaiveDate::from_ymd(2021, 11, 25),
+            NaiveDate::from_ymd(2022, 11, 24),
+        ]);
+
+        let bounds = THANKSGIVING
+            .schedule_between(NaiveDate::from_ymd(2020, 11, 26), NaiveDate::from_ymd(2021, 11, 25))
+            .collect::<Vec<_>>();
+        assert_eq!(bounds, vec![NaiveDate::from_ymd(2020, 11, 26), NaiveDate::from_ymd(2021, 11, 25)]);
+    }
+
+    #[test]
+    fn combined_bounds() {
+        let start = NaiveDate::from_ymd(2020, 1, 1);
+        let end = NaiveDate::from_ymd(2025, 12, 31);
+        assert_eq!(THANKSGIVING.iter().starting_at(start).ending_at(end).count(), 6);
+        assert_eq!(THANKSGIVING.iter().ending_at(end).starting_at(start).count(), 6);
+        assert_eq!(THANKSGIVING.iter().at(NaiveDate::from_ymd(1990, 1, 1)).starting_at(start).ending_at(end).count(), 6);
+        assert_eq!(THANKSGIVING.iter().starting_at(start).ending_at(end).at(start).count(), 6);
+
+        let mut tgives = THANKSGIVING.iter().starting_at(start).ending_at(end);
+        assert_eq!(tgives.next(), Some(NaiveDate::from_ymd(2020, 11, 26)));
+        assert_eq!(tgives.next_back(), None);
+        assert_eq!(tgives.count(), 5);
+    }
+
+    #[test]
+    fn day_of_month_iter() {
+        let halloween = DayOfMonth::new(31, October);
+        let dates = halloween
+            .iter()
+            .at(NaiveDate::from_ymd(2020, 1, 1))
+            .take(3)
+            .collect::<Vec<_>>();
+        assert_eq!(dates, vec![
+            NaiveDate::from_ymd(2020, 10, 31),
+            NaiveDate::from_ymd(2021, 10, 31),
+            NaiveDate::from_ymd(2022, 10, 31),
+        ]);
+    }
+
+    #[test]
+    fn generic_into_iter() {
+        fn next_after<'h, H>(holiday: &'h H, date: NaiveDate) -> Option<NaiveDate>
+        where
+            H: BeforeAfterDate,
+            &'h H: IntoIterator<IntoIter = HolidayIter<'h, H>>,
+        {
+            holiday.into_iter().at(date).next()
+        }
+
+        let date = NaiveDate::from_ymd(2020, 1, 1);
+        let exp = Some(NaiveDate::from_ymd(2020, 11, 26));
+        assert_eq!(next_after(&NthWeekdayOfMonth::new(Fourth, Weekday::Thu, November), date), exp);
+        assert_eq!(next_after(&HolidayDate::FixedDate(DayOfMonth::new(26, November)), date), exp);
+        assert_eq!(next_after(&DayOfMonth::new(26, November), date), exp);
+    }
+
+    #[test]
+    fn christmas_weekends() {
+        let weekends = holidays::global::CHRISTMAS
+            .iter()
+            .at(NaiveDate::from_ymd(2020, 1, 1))
+            .weekends_only()
+            .take(2)
+            .collect::<Vec<_>>();
+        assert_eq!(weekends, vec![NaiveDate::from_ymd(2021, 12, 25), NaiveDate::from_ymd(2022, 12, 25)]);
+
+        assert_eq!(holidays::global::CHRISTMAS.weekend_years(2020, 2030), vec![2021, 2022, 2027]);
+    }
+
+    #[test]
+    fn christmas_mondays() {
+        assert_eq!(
+            holidays::global::CHRISTMAS.years_on_weekday(Weekday::Mon, 2020, 2040),
+            vec![2023, 2028, 2034]
+        );
+    }
+
+    #[test]
+    fn leap_days_between() {
+        let leap_days = holidays::global::LEAP_DAY
+            .iter()
+            .starting_at(NaiveDate::from_ymd(2019, 1, 1))
+            .ending_at(NaiveDate::from_ymd(2033, 12, 31))
+            .collect::<Vec<_>>();
+        assert_eq!(leap_days, vec![
+            NaiveDate::from_ymd(2020, 2, 29),
+            NaiveDate::from_ymd(2024, 2, 29),
+            NaiveDate::from_ymd(2028, 2, 29),
+            NaiveDate::from_ymd(2032, 2, 29),
+        ]);
+    }
+
     /// Assert that Leap Day only occurs in years divisible by 4
     #[test]
     fn take_5() {
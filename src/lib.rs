@@ -24,43 +24,141 @@
 //! assert_eq!(pastover, NaiveDate::from_ymd(2021, 4, 2));
 //! assert_eq!(pastover, NaiveDate::from_ymd(2022, 4, 1));
 //! ```
+//!
+//! Weekdays are also available as constants, so chrono does not need to be imported:
+//!
+//! ```rust
+//! use holiday::{Holiday, NaiveDate, Last, May, MON};
+//!
+//! let memorial_day = Holiday::new_nth("Memorial Day", Last, MON, May);
+//! assert_eq!(memorial_day.in_year(2021), NaiveDate::from_ymd(2021, 5, 31));
+//! ```
 
-pub use chrono::{Local, Datelike, NaiveDate, Date, DateTime, Weekday};
+pub use chrono::{Local, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Date, DateTime, Weekday};
 
 mod eq;
+#[cfg(feature = "icalendar")]
+mod ical;
+mod ops;
+mod packed;
 pub mod before_after;
+pub mod calendar;
+pub mod display;
+pub mod easter;
+pub mod error;
+pub mod hijri;
 pub mod holidays;
 pub mod iter;
+pub mod observed;
+pub mod parse;
+pub mod relative;
+pub mod season;
+pub mod span;
 
 pub use before_after::*;
+pub use calendar::*;
+pub use display::*;
+pub use error::*;
 pub use iter::*;
+pub use observed::*;
+pub use parse::*;
+pub use relative::*;
+pub use season::*;
+pub use span::*;
 use HolidayDate::*;
 pub use NthWeekday::*;
 pub use Month::*;
+pub use Weekday::*;
+
+/// Monday
+pub const MON: Weekday = Weekday::Mon;
+/// Tuesday
+pub const TUE: Weekday = Weekday::Tue;
+/// Wednesday
+pub const WED: Weekday = Weekday::Wed;
+/// Thursday
+pub const THU: Weekday = Weekday::Thu;
+/// Friday
+pub const FRI: Weekday = Weekday::Fri;
+/// Saturday
+pub const SAT: Weekday = Weekday::Sat;
+/// Sunday
+pub const SUN: Weekday = Weekday::Sun;
 
 /// An annually repeating calendar date.
 /// Can be either a fixed date (e.g., April 1) or an nth weekday of the month (e.g., 4th Thursday
 /// in November)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Holiday<S> {
     name: S,
     date: HolidayDate,
+    observance: Observance,
+    time: Option<NaiveTime>,
+    valid_from: Option<i32>,
+    recurrence: Recurrence,
+    names: &'static [(&'static str, &'static str)],
 }
 
 impl<S: ToString> Holiday<S> {
+    /// Creates a new Holiday from any HolidayDate
+    pub const fn new(name: S, date: HolidayDate) -> Self {
+        Holiday {
+            name,
+            date,
+            observance: Observance::FullDay,
+            time: None,
+            valid_from: None,
+            recurrence: Recurrence::Annual,
+            names: &[],
+        }
+    }
+
     /// Creates a new fixed date holiday
     pub fn new_fixed<M: Into<Month>>(name: S, month: M, day: u32) -> Self {
         Holiday {
             name,
             date: HolidayDate::FixedDate(DayOfMonth { month: month.into(), day }),
+            observance: Observance::FullDay,
+            time: None,
+            valid_from: None,
+            recurrence: Recurrence::Annual,
+            names: &[],
         }
     }
 
+    /// Creates a new fixed date holiday, like `new_fixed`, but returns `HolidayError::InvalidInput`
+    /// if the month never has the day, e.g., April 31 or February 30. February 29 is allowed.
+    pub fn new_fixed_checked<M: Into<Month>>(name: S, month: M, day: u32) -> Result<Self, HolidayError> {
+        let month = month.into();
+        if day < 1 || day > month.max_days() {
+            return Err(HolidayError::InvalidInput(DayOfMonth { month, day }.to_string()));
+        }
+        Ok(Holiday::new_fixed(name, month, day))
+    }
+
+    /// Creates a new fixed date holiday observed on a weekday when it falls on a weekend, e.g.,
+    /// Independence Day observed on Friday, July 3 when July 4 is a Saturday
+    pub fn new_fixed_observed<M: Into<Month>>(name: S, month: M, day: u32, shift: WeekendShift) -> Self {
+        Holiday::new(
+            name,
+            HolidayDate::Substitute {
+                base: Box::new(HolidayDate::FixedDate(DayOfMonth { month: month.into(), day })),
+                avoid: Vec::new(),
+                shift,
+            },
+        )
+    }
+
     /// Creates a new nth weekday of the month Holiday
     pub fn new_nth<N: Into<NthWeekday>, M: Into<Month>>(name: S, nth: N, weekday: Weekday, month: M) -> Self {
         Holiday {
             name,
             date: HolidayDate::NthDate(NthWeekdayOfMonth::new(nth, weekday, month)),
+            observance: Observance::FullDay,
+            time: None,
+            valid_from: None,
+            recurrence: Recurrence::Annual,
+            names: &[],
         }
     }
 
@@ -69,16 +167,354 @@ impl<S: ToString> Holiday<S> {
         &self.name
     }
 
+    /// Returns a copy of the Holiday with a new name, keeping the same date rule
+    pub fn with_name<T: ToString>(&self, name: T) -> Holiday<T> {
+        Holiday {
+            name,
+            date: self.date.clone(),
+            observance: self.observance,
+            time: self.time,
+            valid_from: self.valid_from,
+            recurrence: self.recurrence,
+            names: self.names,
+        }
+    }
+
+    /// Determine if two Holidays have the same date rule, regardless of their names
+    pub fn same_date<T>(&self, other: &Holiday<T>) -> bool {
+        self.date == other.date
+    }
+
+    /// Returns the name of the Holiday in a given language, falling back to the primary name if
+    /// there is no name for the language
+    pub fn localized_name(&self, lang: &str) -> &str
+    where
+        S: AsRef<str>,
+    {
+        self.names
+            .iter()
+            .find(|(name_lang, _name)| *name_lang == lang)
+            .map(|(_name_lang, name)| *name)
+            .unwrap_or_else(|| self.name.as_ref())
+    }
+
+    /// Returns how much of the day the Holiday is observed
+    pub fn observance(&self) -> Observance {
+        self.observance
+    }
+
+    /// Set how much of the day the Holiday is observed
+    pub fn with_observance(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    /// Returns the time of day the Holiday starts, if any
+    pub fn time(&self) -> Option<NaiveTime> {
+        self.time
+    }
+
+    /// Set the time of day the Holiday starts, e.g., 20:00 for an evening celebration
+    pub const fn with_time(mut self, time: NaiveTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Returns the first year the Holiday was observed, if it has one
+    pub fn valid_from(&self) -> Option<i32> {
+        self.valid_from
+    }
+
+    /// Set the first year the Holiday was observed, e.g., 1966 for Kwanzaa. The Holiday has no
+    /// occurrences before that year.
+    pub const fn with_valid_from(mut self, year: i32) -> Self {
+        self.valid_from = Some(year);
+        self
+    }
+
+    /// Returns how often the Holiday repeats
+    pub fn recurrence(&self) -> Recurrence {
+        self.recurrence
+    }
+
+    /// Set how often the Holiday repeats, e.g., monthly for a payday on the 15th
+    pub const fn with_recurrence(mut self, recurrence: Recurrence) -> Self {
+        self.recurrence = recurrence;
+        self
+    }
+
+    /// The first day of the first year the Holiday was observed, if it has one
+    pub(crate) fn valid_start(&self) -> Option<NaiveDate> {
+        self.valid_from.and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1))
+    }
+
+    /// The date and time of the Holiday in a given year. Holidays without a time of day start at
+    /// midnight.
+    pub fn datetime_in_year(&self, year: i32) -> NaiveDateTime {
+        self.in_year(year).and_time(self.time.unwrap_or(NaiveTime::MIN))
+    }
+
+    /// Set the localized names of the Holiday as pairs of language codes and names, e.g.,
+    /// `&[("de", "Weihnachten")]`
+    pub const fn with_names(mut self, names: &'static [(&'static str, &'static str)]) -> Self {
+        self.names = names;
+        self
+    }
+
     /// Returns an iterator over all the occurrences of a given Holiday starting at the earliest
     /// representable date.
     pub fn iter(&self) -> HolidayIter<Self> {
         self.into_iter()
     }
 
-    /// Determine the date of a Holiday in a given year
+    /// The next occurrence of the Holiday at a given local time in a time zone, after now
+    pub fn next_datetime_in_tz<Tz: chrono::TimeZone>(&self, time: chrono::NaiveTime, tz: &Tz) -> DateTime<Tz> {
+        self.datetime_after(&chrono::Utc::now().with_timezone(tz), time)
+    }
+
+    /// The next occurrence of the Holiday at a given local time, at or after a given instant.
+    /// If the local time does not exist on the Holiday because of a daylight saving time gap, the
+    /// first instant after the gap is used. If it is ambiguous, the earlier instant is used.
+    pub fn datetime_after<Tz: chrono::TimeZone>(&self, from: &DateTime<Tz>, time: chrono::NaiveTime) -> DateTime<Tz> {
+        let tz = from.timezone();
+        let mut date = self.after(&from.naive_local().date());
+        loop {
+            let mut local = date.and_time(time);
+            let datetime = loop {
+                match tz.from_local_datetime(&local) {
+                    chrono::LocalResult::Single(datetime) => break datetime,
+                    chrono::LocalResult::Ambiguous(earliest, _latest) => break earliest,
+                    chrono::LocalResult::None => local += chrono::Duration::minutes(1),
+                }
+            };
+
+            if &datetime >= from {
+                break datetime;
+            }
+            date = date.succ_opt().and_then(|next| self.try_after(&next)).expect("no occurrence after date");
+        }
+    }
+
+    /// The long weekend around the Holiday in a given year, as the first and last dates (inclusive),
+    /// or `None` if the Holiday does not occur that year. The window always includes the Holiday: a
+    /// Monday holiday gives Saturday to Monday and a Friday holiday gives Friday to Sunday. A
+    /// weekend holiday gives its own Saturday and Sunday. Holidays on a Tuesday or Wednesday reach
+    /// back to the weekend before, and holidays on a Thursday reach forward to the weekend after.
+    pub fn surrounding_weekend(&self, year: i32) -> Option<(NaiveDate, NaiveDate)> {
+        let date = self.try_in_year(year)?;
+        let (start, end) = match date.weekday() {
+            Weekday::Mon => (-2, 0),
+            Weekday::Tue => (-3, 0),
+            Weekday::Wed => (-4, 0),
+            Weekday::Thu => (0, 3),
+            Weekday::Fri => (0, 2),
+            Weekday::Sat => (0, 1),
+            Weekday::Sun => (-1, 0),
+        };
+        Some((
+            date.checked_add_signed(chrono::Duration::days(start))?,
+            date.checked_add_signed(chrono::Duration::days(end))?,
+        ))
+    }
+
+    /// The occurrence of the Holiday nearest to a given date. Ties go to the later occurrence.
+    pub fn nearest(&self, date: &NaiveDate) -> NaiveDate {
+        let before = self.before(date);
+        let after = self.after(date);
+        if *date - before < after - *date {
+            before
+        } else {
+            after
+        }
+    }
+
+    /// Shift the Holiday by a number of days. Fixed dates are shifted in place, rolling over month
+    /// boundaries. Other dates are offset from the original date rule.
+    pub fn shift_by(mut self, days: i32) -> Holiday<S> {
+        self.date = self.date.shift_by(days);
+        self
+    }
+
+    /// The day before the Holiday, named like "Christmas Eve"
+    pub fn eve(&self) -> Holiday<String> {
+        self.with_name(format!("{} Eve", self.name.to_string())).shift_by(-1)
+    }
+
+    /// The day after the Holiday, named like "Day after Christmas"
+    pub fn morrow(&self) -> Holiday<String> {
+        self.with_name(format!("Day after {}", self.name.to_string())).shift_by(1)
+    }
+
+    /// Returns the next `n` occurrences of the Holiday, including the given date, in ascending order
+    pub fn next_n(&self, from: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        self.iter().at(from).take(n).collect()
+    }
+
+    /// Returns the previous `n` occurrences of the Holiday, excluding the given date, in descending
+    /// order
+    pub fn previous_n(&self, from: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        std::iter::successors(self.try_before(&from), |date| self.try_before(date)).take(n).collect()
+    }
+
+    /// Returns a lazy iterator over the occurrences of the Holiday between two dates (inclusive)
+    pub fn schedule_between(&self, start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.iter().at(start).starting_at(start).ending_at(end)
+    }
+
+    /// Returns the years in a range (inclusive) in which the Holiday falls on a weekend
+    pub fn weekend_years(&self, start: i32, end: i32) -> Vec<i32> {
+        NaiveDate::from_ymd_opt(start.max(NaiveDate::MIN.year()), 1, 1)
+            .into_iter()
+            .flat_map(|first_day| self.iter().at(first_day))
+            .take_while(|date| date.year() <= end)
+            .filter(|date| date.is_weekend())
+            .map(|date| date.year())
+            .collect()
+    }
+
+    /// Returns the years in a range (inclusive) in which the Holiday falls on a given weekday
+    pub fn years_on_weekday(&self, weekday: Weekday, start: i32, end: i32) -> Vec<i32> {
+        NaiveDate::from_ymd_opt(start.max(NaiveDate::MIN.year()), 1, 1)
+            .into_iter()
+            .flat_map(|first_day| self.iter().at(first_day))
+            .take_while(|date| date.year() <= end)
+            .filter(|date| date.weekday() == weekday)
+            .map(|date| date.year())
+            .collect()
+    }
+
+    /// Returns the occurrences of the Holiday in the years within a radius of a given year, e.g.,
+    /// 2020 through 2022 for a radius of 1 around 2021, paired with their years. Years without an
+    /// occurrence are skipped.
+    pub fn around(&self, year: i32, radius: i32) -> Vec<(i32, NaiveDate)> {
+        (year - radius..=year + radius)
+            .flat_map(|year| self.occurrences_in_year(year).into_iter().map(move |date| (year, date)))
+            .collect()
+    }
+
+    /// Count the occurrences of the Holiday on each weekday in a range of years (inclusive),
+    /// indexed by `Weekday::num_days_from_monday`
+    pub fn weekday_distribution(&self, start: i32, end: i32) -> [u32; 7] {
+        let mut counts = [0; 7];
+        for date in (start..=end).flat_map(|year| self.occurrences_in_year(year)) {
+            counts[date.weekday().num_days_from_monday() as usize] += 1;
+        }
+        counts
+    }
+
+    /// Count the occurrences of the Holiday on each weekday over one full 400 year Gregorian cycle,
+    /// after which the calendar repeats, indexed by `Weekday::num_days_from_monday`. A fixed date
+    /// occurs 400 times, but not evenly across the weekdays.
+    pub fn weekday_cycle(&self) -> [u32; 7] {
+        self.weekday_distribution(2000, 2399)
+    }
+
+    /// Returns every date in a given year on which the Holiday occurs: zero, one, or two dates
+    pub fn occurrences_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        if matches!(self.valid_from, Some(valid_from) if year < valid_from) {
+            return Vec::new();
+        }
+
+        match (self.recurrence, &self.date) {
+            (Recurrence::Monthly, FixedDate(day_of_month)) => (1..=12)
+                .filter_map(|month| NaiveDate::from_ymd_opt(year, month, day_of_month.day))
+                .collect(),
+            (Recurrence::Monthly, NthDate(nth)) => (1..=12)
+                .filter_map(|month| NthWeekdayOfMonth::new(nth.nth, nth.weekday, month).to_date(year))
+                .collect(),
+            (Recurrence::EveryNYears(n), _) if (year - self.valid_from.unwrap_or(0)).rem_euclid(n.max(1) as i32) != 0 => {
+                Vec::new()
+            },
+            _ => self.date.occurrences_in_year(year),
+        }
+    }
+
+    /// Determine the date of a Holiday in a given year. For lunar holidays that occur twice in a
+    /// year, this is the first occurrence; for holidays that do not occur in a year, this is the
+    /// next occurrence after the year.
     pub fn in_year(&self, year: i32) -> NaiveDate {
         self.after(&NaiveDate::from_ymd(year, 1, 1))
     }
+
+    /// Determine if the Holiday falls before a given date in a given year (see `in_year`)
+    pub fn is_before(&self, other: &NaiveDate, year: i32) -> bool {
+        self.in_year(year) < *other
+    }
+
+    /// Determine if the Holiday falls after a given date in a given year (see `in_year`)
+    pub fn is_after(&self, other: &NaiveDate, year: i32) -> bool {
+        self.in_year(year) > *other
+    }
+
+    /// Determine the first date of a Holiday in a given year, or `None` if it does not occur that
+    /// year or the year is outside the range its date rule supports (see
+    /// `HolidayDate::min_supported_year`), e.g., Easter before 1583
+    pub fn try_in_year(&self, year: i32) -> Option<NaiveDate> {
+        if !self.date.supports_year(year) {
+            return None;
+        }
+        self.occurrences_in_year(year).into_iter().next()
+    }
+
+    /// Determine if the Holiday falls on a different month and day from year to year. See
+    /// `HolidayDate::is_movable`.
+    pub fn is_movable(&self) -> bool {
+        self.date.is_movable()
+    }
+
+    /// Report a Holiday that has no occurrence in some years: one recurring every few years, or
+    /// with a date rule that does not occur every year (see `HolidayDate::validate`)
+    pub fn validate(&self) -> Result<(), HolidayError> {
+        match self.recurrence {
+            Recurrence::EveryNYears(n) if n > 1 => Err(HolidayError::NotEveryYear(self.name.to_string())),
+            _ => self
+                .date
+                .validate()
+                .map_err(|_| HolidayError::NotEveryYear(self.name.to_string())),
+        }
+    }
+
+    /// The number of days from a given date until the next occurrence of the Holiday, including
+    /// the given date. See `HolidayDate::days_until_fast`.
+    pub fn days_until_fast(&self, from: NaiveDate) -> i64 {
+        match self.valid_start() {
+            Some(start) if from < start => (self.after(&from) - from).num_days(),
+            _ if self.recurrence != Recurrence::Annual => (self.after(&from) - from).num_days(),
+            _ => self.date.days_until_fast(from),
+        }
+    }
+
+    /// The number of days since the most recent occurrence of the Holiday before a given date.
+    /// Since `before` excludes the given date, a date on the Holiday counts back to the previous
+    /// occurrence, e.g., a year for a fixed date.
+    pub fn days_since(&self, from: NaiveDate) -> i64 {
+        (from - self.before(&from)).num_days()
+    }
+
+    /// Determine the ordinal day of the year (January 1 = 1) of a Holiday in a given year
+    pub fn day_of_year_in(&self, year: i32) -> u32 {
+        self.in_year(year).ordinal()
+    }
+
+    /// Describe the number of days from a given date until the next occurrence of a Holiday.
+    /// Example: "42 days until Christmas (Sat 25 Dec 2021)"
+    pub fn countdown_string(&self, from: NaiveDate) -> String {
+        let next = self.after(&from);
+        let date = next.format(COUNTDOWN_FORMAT);
+        match (next - from).num_days() {
+            0 => format!("{} is today! ({})", self.name.to_string(), date),
+            1 => format!("1 day until {} ({})", self.name.to_string(), date),
+            days => format!("{} days until {} ({})", days, self.name.to_string(), date),
+        }
+    }
+}
+
+const COUNTDOWN_FORMAT: &str = "%a %-d %b %Y";
+
+/// Sort Holidays by their dates in a given year (see `Holiday::in_year`), rather than by their
+/// date rules as `Ord` does. Holidays on the same date keep their order.
+pub fn sort_by_year<S: ToString>(holidays: &mut [Holiday<S>], year: i32) {
+    holidays.sort_by_cached_key(|holiday| holiday.in_year(year));
 }
 
 #[test]
@@ -89,14 +525,316 @@ fn holiday_in_year() {
     assert_eq!(holidays::global::NEW_YEARS_EVE.in_year(2020), NaiveDate::from_ymd(2020, 12, 31));
 }
 
+#[test]
+fn holiday_reexported_weekdays() {
+    let tgives = Holiday::new_nth("Thanksgiving", Fourth, Thu, November);
+    assert_eq!(tgives, holidays::united_states::THANKSGIVING);
+}
+
+#[test]
+fn holiday_day_of_year_in() {
+    assert_eq!(holidays::global::NEW_YEARS_DAY.day_of_year_in(2021), 1);
+    assert_eq!(holidays::global::NEW_YEARS_EVE.day_of_year_in(2021), 365);
+    assert_eq!(holidays::global::NEW_YEARS_EVE.day_of_year_in(2020), 366);
+}
+
+#[test]
+fn holiday_shift_by() {
+    let black_friday = holidays::united_states::THANKSGIVING.shift_by(1);
+    assert_eq!(black_friday.in_year(2020), NaiveDate::from_ymd(2020, 11, 27));
+
+    let boxing_day = holidays::global::CHRISTMAS.shift_by(1);
+    assert_eq!(boxing_day.date, FixedDate(DayOfMonth::new(26, December)));
+
+    let new_years_day = holidays::global::NEW_YEARS_EVE.shift_by(1);
+    assert_eq!(new_years_day.date, FixedDate(DayOfMonth::new(1, January)));
+
+    let day_after = Holiday::new_fixed("February 28th", February, 28).shift_by(1);
+    assert_eq!(day_after.in_year(2020), NaiveDate::from_ymd(2020, 2, 29));
+    assert_eq!(day_after.in_year(2021), NaiveDate::from_ymd(2021, 3, 1));
+}
+
+#[test]
+fn eve_and_morrow() {
+    use holidays::{global::*, united_states::*};
+    let christmas_eve = CHRISTMAS.eve();
+    assert_eq!(christmas_eve.name(), "Christmas Eve");
+    assert_eq!(christmas_eve.in_year(2021), NaiveDate::from_ymd(2021, 12, 24));
+    assert!(christmas_eve.same_date(&CHRISTMAS_EVE));
+
+    let black_friday = THANKSGIVING.morrow();
+    assert_eq!(black_friday.name(), "Day after Thanksgiving");
+    assert_eq!(black_friday.in_year(2021), NaiveDate::from_ymd(2021, 11, 26));
+}
+
+#[test]
+fn holiday_date_recurrence_desc() {
+    use holidays::{global::*, united_states::*};
+    assert_eq!(CHRISTMAS.date.recurrence_desc(), "fixed 12-25");
+    assert_eq!(THANKSGIVING.date.recurrence_desc(), "nth 4 Thu 11");
+    assert_eq!(MEMORIAL_DAY.date.recurrence_desc(), "last Mon 5");
+    assert_eq!(ADVENT_1.date.recurrence_desc(), "weekday -4 Sun 12-25");
+    assert_eq!(THANKSGIVING.shift_by(1).date.recurrence_desc(), "(nth 4 Thu 11)+1");
+    assert_eq!(FromYearEnd { offset_days: 1 }.recurrence_desc(), "yearend-1");
+}
+
+#[test]
+fn from_year_end() {
+    let date = FromYearEnd { offset_days: 1 };
+    for year in 2019..=2021 {
+        assert_eq!(date.after(&NaiveDate::from_ymd(year, 1, 1)), NaiveDate::from_ymd(year, 12, 30));
+    }
+    assert_eq!(date, NaiveDate::from_ymd(2020, 12, 30));
+    assert_ne!(date, NaiveDate::from_ymd(2020, 12, 31));
+}
+
+#[test]
+fn holiday_next_previous_n() {
+    let christmas = holidays::global::CHRISTMAS;
+    assert_eq!(christmas.next_n(NaiveDate::from_ymd(2020, 12, 25), 2), vec![
+        NaiveDate::from_ymd(2020, 12, 25),
+        NaiveDate::from_ymd(2021, 12, 25),
+    ]);
+    assert_eq!(christmas.previous_n(NaiveDate::from_ymd(2021, 1, 1), 2), vec![
+        NaiveDate::from_ymd(2020, 12, 25),
+        NaiveDate::from_ymd(2019, 12, 25),
+    ]);
+    assert_eq!(christmas.previous_n(NaiveDate::from_ymd(2020, 12, 26), 1), vec![
+        NaiveDate::from_ymd(2020, 12, 25),
+    ]);
+    assert_eq!(christmas.previous_n(NaiveDate::from_ymd(2020, 12, 25), 1), vec![
+        NaiveDate::from_ymd(2019, 12, 25),
+    ]);
+}
+
+#[test]
+fn holiday_nearest() {
+    let christmas = holidays::global::CHRISTMAS;
+    assert_eq!(christmas.nearest(&NaiveDate::from_ymd(2021, 6, 25)), NaiveDate::from_ymd(2020, 12, 25));
+    assert_eq!(christmas.nearest(&NaiveDate::from_ymd(2021, 6, 26)), NaiveDate::from_ymd(2021, 12, 25));
+    assert_eq!(christmas.nearest(&NaiveDate::from_ymd(2021, 12, 25)), NaiveDate::from_ymd(2021, 12, 25));
+
+    // 2019-12-25 and 2020-12-25 are both 183 days from 2020-06-25
+    assert_eq!(christmas.nearest(&NaiveDate::from_ymd(2020, 6, 25)), NaiveDate::from_ymd(2020, 12, 25));
+}
+
+#[test]
+fn holiday_datetime_after() {
+    use chrono::{NaiveTime, TimeZone, Utc};
+    let noon = NaiveTime::from_hms(12, 0, 0);
+    let christmas = holidays::global::CHRISTMAS;
+
+    let from = Utc.ymd(2021, 12, 25).and_hms(11, 0, 0);
+    assert_eq!(christmas.datetime_after(&from, noon), Utc.ymd(2021, 12, 25).and_hms(12, 0, 0));
+
+    let from = Utc.ymd(2021, 12, 25).and_hms(13, 0, 0);
+    assert_eq!(christmas.datetime_after(&from, noon), Utc.ymd(2022, 12, 25).and_hms(12, 0, 0));
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn holiday_datetime_after_tz() {
+    use chrono::{NaiveTime, TimeZone, Utc};
+    use chrono_tz::America::New_York;
+
+    let from = New_York.ymd(2021, 6, 1).and_hms(0, 0, 0);
+    let midnight = NaiveTime::from_hms(0, 0, 0);
+    let ball_drop = holidays::global::NEW_YEARS_DAY.datetime_after(&from, midnight);
+    assert_eq!(ball_drop, New_York.ymd(2022, 1, 1).and_hms(0, 0, 0));
+    assert_eq!(ball_drop.with_timezone(&Utc), Utc.ymd(2022, 1, 1).and_hms(5, 0, 0));
+
+    // 2:30 AM does not exist on the day daylight saving time starts
+    let half_past_two = NaiveTime::from_hms(2, 30, 0);
+    let dst_start = holidays::united_states::DST_START.datetime_after(&from, half_past_two);
+    assert_eq!(dst_start.with_timezone(&Utc), Utc.ymd(2022, 3, 13).and_hms(7, 0, 0));
+}
+
+#[test]
+fn holiday_countdown_string() {
+    let christmas = holidays::global::CHRISTMAS;
+    assert_eq!(
+        christmas.countdown_string(NaiveDate::from_ymd(2021, 12, 25)),
+        "Christmas is today! (Sat 25 Dec 2021)"
+    );
+    assert_eq!(
+        christmas.countdown_string(NaiveDate::from_ymd(2021, 12, 24)),
+        "1 day until Christmas (Sat 25 Dec 2021)"
+    );
+    assert_eq!(
+        christmas.countdown_string(NaiveDate::from_ymd(2021, 11, 13)),
+        "42 days until Christmas (Sat 25 Dec 2021)"
+    );
+    assert_eq!(
+        holidays::global::NEW_YEARS_DAY.countdown_string(NaiveDate::from_ymd(2021, 12, 1)),
+        "31 days until New Year's Day (Sat 1 Jan 2022)"
+    );
+}
+
+/// How much of the day a Holiday is observed
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Observance {
+    /// Observed for the entire day
+    #[default]
+    FullDay,
+    /// Observed for half of the day (e.g., a half day of work on Christmas Eve)
+    HalfDay,
+    /// Observed only in the evening, after the work day
+    EveningOnly,
+}
+
+impl Observance {
+    /// The fraction of a regular work day worked on a day with this observance
+    pub fn work_fraction(&self) -> f32 {
+        match self {
+            Observance::FullDay => 0.0,
+            Observance::HalfDay => 0.5,
+            Observance::EveningOnly => 1.0,
+        }
+    }
+}
+
+/// How often a Holiday repeats
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Recurrence {
+    /// Once a year
+    #[default]
+    Annual,
+    /// Every month on the same day of the month or nth weekday, e.g., the 15th or the 1st Friday.
+    /// Months without the day are skipped. Other date rules repeat annually.
+    Monthly,
+    /// Once every n years, counted from the year the Holiday was first observed (see
+    /// `Holiday::with_valid_from`), or from year 0 if it has none
+    EveryNYears(u32),
+}
+
 /// Holiday Date type
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum HolidayDate {
     /// Fixed date. Example: "October 31"
     FixedDate(DayOfMonth),
 
     /// Relative weekday in a month. Example: "4th Thursday in November"
     NthDate(NthWeekdayOfMonth),
+
+    /// Nth weekday before or after a fixed anchor date, excluding the anchor itself.
+    /// A positive count searches forward, a negative count searches backward, and a count of zero
+    /// is treated as one. Example: "4th Sunday before December 25"
+    WeekdayRelative {
+        /// The weekday to find
+        weekday: Weekday,
+        /// The date to count from
+        anchor: DayOfMonth,
+        /// Which occurrence of the weekday to find: negative counts search backward
+        count: i32,
+    },
+
+    /// Another HolidayDate offset by a number of days. Example: "1 day after Thanksgiving"
+    Offset {
+        /// The date rule to offset from
+        base: Box<HolidayDate>,
+        /// The number of days to offset: negative offsets are before the base date
+        days: i32,
+    },
+
+    /// A number of days before the end of the year. Example: "1 day before December 31"
+    FromYearEnd {
+        /// The number of days before December 31: an offset of 0 is December 31
+        offset_days: u32,
+    },
+
+    /// A fixed date in the tabular Islamic calendar, which may occur zero, one, or two times in a
+    /// Gregorian year. Example: "1 Shawwal"
+    Hijri {
+        /// The Islamic month (Muharram = 1)
+        month: u32,
+        /// The day of the Islamic month
+        day: u32,
+    },
+
+    /// Another HolidayDate shifted off weekends, then moved to the next day while it collides with
+    /// any of a list of other dates. Example: "Boxing Day, or the next weekday after Christmas"
+    Substitute {
+        /// The date rule to substitute
+        base: Box<HolidayDate>,
+        /// The date rules the substitute may not fall on
+        avoid: Vec<HolidayDate>,
+        /// How the date is shifted when it falls on a weekend
+        shift: WeekendShift,
+    },
+
+    /// The nth weekday counting from the first day of a month, continuing into later months.
+    /// A count of zero is treated as one. Example: "8th Monday from January"
+    NthWeekdayAbsolute {
+        /// Which occurrence of the weekday to find
+        count: u32,
+        /// The weekday to find
+        weekday: Weekday,
+        /// The month to start counting from
+        from_month: Month,
+    },
+
+    /// The last weekday (Monday to Friday) of a month, regardless of which weekday it is.
+    /// Example: "last business day of May"
+    LastBusinessDay {
+        /// The month
+        month: Month,
+    },
+
+    /// A day of the year, counting January 1 as 1. Day 366 only occurs in leap years.
+    /// Example: "100th day of the year"
+    DayOfYear {
+        /// The day of the year (January 1 = 1)
+        ordinal: u32,
+    },
+
+    /// A number of days before or after Easter Sunday. Example: "2 days before Easter"
+    EasterRelative {
+        /// The number of days from Easter Sunday: negative offsets are before Easter
+        days: i32,
+    },
+
+    /// A series of consecutive weekly occurrences of a weekday before or after a fixed anchor
+    /// date, excluding the anchor itself. Example: "the 4 Sundays before December 25"
+    WeeklySeries {
+        /// The weekday of the series
+        weekday: Weekday,
+        /// The number of weeks in the series
+        count: u32,
+        /// The date the series counts from
+        anchor: DayOfMonth,
+        /// Whether the series falls before or after the anchor
+        direction: SeriesDirection,
+    },
+
+    /// Dates looked up in a table of (year, month, day) entries, for dates that cannot be
+    /// calculated, like those of festivals that follow the moon. There is no occurrence in years
+    /// missing from the table. Example: "the full moon of May"
+    Table(&'static [(i32, u32, u32)]),
+
+    /// The weekday nearest to a fixed date: a Saturday moves to the Friday before, a Sunday to the
+    /// Monday after. Example: "the weekday nearest to July 4"
+    NearestWeekday {
+        /// The fixed date
+        anchor: DayOfMonth,
+    },
+
+    /// The first given weekday on or after another HolidayDate. Example: "the Monday on or after
+    /// Easter"
+    WeekdayAfter {
+        /// The HolidayDate to start from
+        base: Box<HolidayDate>,
+        /// The weekday to move to
+        weekday: Weekday,
+    },
+}
+
+/// Whether a `HolidayDate::WeeklySeries` falls before or after its anchor date
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum SeriesDirection {
+    /// The series ends the week before the anchor
+    Before,
+    /// The series starts the week after the anchor
+    After,
 }
 
 impl HolidayDate {
@@ -104,6 +842,291 @@ impl HolidayDate {
     pub fn iter(&self) -> HolidayIter<Self> {
         self.into_iter()
     }
+
+    /// Shift the HolidayDate by a number of days. See `Holiday::shift_by`.
+    pub(crate) fn shift_by(self, days: i32) -> HolidayDate {
+        match self {
+            FixedDate(day_of_month) => day_of_month.shift_by(days).map(FixedDate).unwrap_or(Offset {
+                base: Box::new(FixedDate(day_of_month)),
+                days,
+            }),
+            Offset { base, days: base_days } => Offset { base, days: base_days.saturating_add(days) },
+            date => Offset { base: Box::new(date), days },
+        }
+    }
+
+    /// A compact, machine-readable summary of the date rule.
+    /// Examples: "fixed 12-25", "nth 4 Thu 11", "last Mon 5"
+    pub fn recurrence_desc(&self) -> String {
+        match self {
+            FixedDate(dom) => format!("fixed {:02}-{:02}", dom.month as u32, dom.day),
+            NthDate(nth) if nth.nth == Last => format!("last {:?} {}", nth.weekday, nth.month as u32),
+            NthDate(nth) => format!("nth {} {:?} {}", nth.nth as u32, nth.weekday, nth.month as u32),
+            WeekdayRelative { weekday, anchor, count } => {
+                format!("weekday {:+} {:?} {:02}-{:02}", count, weekday, anchor.month as u32, anchor.day)
+            }
+            Offset { base, days } => format!("({}){:+}", base.recurrence_desc(), days),
+            FromYearEnd { offset_days } => format!("yearend-{}", offset_days),
+            Hijri { month, day } => format!("hijri {:02}-{:02}", month, day),
+            Substitute { base, avoid, shift } => format!(
+                "({}) avoid [{}] {:?}",
+                base.recurrence_desc(),
+                avoid.iter().map(HolidayDate::recurrence_desc).collect::<Vec<_>>().join(", "),
+                shift,
+            ),
+            NthWeekdayAbsolute { count, weekday, from_month } => {
+                format!("abs {} {} from {}", count, weekday, *from_month as u32)
+            }
+            LastBusinessDay { month } => format!("lastbusiness {}", *month as u32),
+            DayOfYear { ordinal } => format!("ordinal {}", ordinal),
+            EasterRelative { days } => format!("easter{:+}", days),
+            WeeklySeries { weekday, count, anchor, direction } => format!(
+                "weekly {} {} {} {:02}-{:02}",
+                count,
+                weekday,
+                match direction {
+                    SeriesDirection::Before => "before",
+                    SeriesDirection::After => "after",
+                },
+                anchor.month as u32,
+                anchor.day,
+            ),
+            Table(entries) => match (entries.first(), entries.last()) {
+                (Some((first, ..)), Some((last, ..))) => format!("table {}..{}", first, last),
+                _ => "table".to_string(),
+            },
+            NearestWeekday { anchor } => {
+                format!("nearest-weekday {:02}-{:02}", anchor.month as u32, anchor.day)
+            }
+            WeekdayAfter { base, weekday } => format!("({}) onafter {:?}", base.recurrence_desc(), weekday),
+        }
+    }
+
+    /// Determine if the date rule falls on a different month and day from year to year, like an
+    /// nth weekday, Easter-relative, or lunar date. Fixed dates, and offsets of fixed dates that
+    /// keep the same month and day, are not movable.
+    pub fn is_movable(&self) -> bool {
+        match self {
+            FixedDate(_) | FromYearEnd { .. } => false,
+            Offset { base, days } => match base.as_ref() {
+                FixedDate(day_of_month) => day_of_month.shift_by(*days).is_none(),
+                base => base.is_movable(),
+            },
+            _ => true,
+        }
+    }
+
+    /// The first year in which the algorithm behind the date rule gives correct dates, if it has
+    /// one: 1583 for dates relative to Easter (see `easter::MIN_SUPPORTED_YEAR`), 622 for Hijri
+    /// dates, and the first year of a Table. Dates are computed in the proleptic Gregorian
+    /// calendar, so fixed dates and nth weekdays have no limit. Easter and Hijri dates have no
+    /// occurrences before their first supported year.
+    pub fn min_supported_year(&self) -> Option<i32> {
+        match self {
+            EasterRelative { .. } => Some(easter::MIN_SUPPORTED_YEAR),
+            Hijri { .. } => Some(hijri::MIN_SUPPORTED_YEAR),
+            Table(entries) => entries.iter().map(|(year, _month, _day)| *year).min(),
+            Offset { base, .. } | Substitute { base, .. } | WeekdayAfter { base, .. } => base.min_supported_year(),
+            _ => None,
+        }
+    }
+
+    /// The last year in which the algorithm behind the date rule gives correct dates, if it has
+    /// one: the last year of a Table. The Easter and Hijri algorithms have no upper limit.
+    pub fn max_supported_year(&self) -> Option<i32> {
+        match self {
+            Table(entries) => entries.iter().map(|(year, _month, _day)| *year).max(),
+            Offset { base, .. } | Substitute { base, .. } | WeekdayAfter { base, .. } => base.max_supported_year(),
+            _ => None,
+        }
+    }
+
+    /// Determine if a year is within the supported range of the date rule, see
+    /// `min_supported_year` and `max_supported_year`
+    pub fn supports_year(&self, year: i32) -> bool {
+        !matches!(self.min_supported_year(), Some(min) if year < min)
+            && !matches!(self.max_supported_year(), Some(max) if year > max)
+    }
+
+    /// Report a date rule that has no occurrence in some years: a fixed date or anchor that only
+    /// exists in leap years (February 29) or a 5th weekday of a month. The date rule is still
+    /// usable.
+    pub fn validate(&self) -> Result<(), HolidayError> {
+        match self {
+            // 2001 is not a leap year
+            FixedDate(day_of_month)
+            | WeekdayRelative { anchor: day_of_month, .. }
+            | WeeklySeries { anchor: day_of_month, .. }
+            | NearestWeekday { anchor: day_of_month }
+                if day_of_month.to_date(2001).is_none() =>
+            {
+                Err(HolidayError::NotEveryYear(self.recurrence_desc()))
+            }
+            NthDate(nth) if nth.nth == NthWeekday::Fifth => Err(HolidayError::NotEveryYear(self.recurrence_desc())),
+            DayOfYear { ordinal } if *ordinal > 365 => Err(HolidayError::NotEveryYear(self.recurrence_desc())),
+            Table(_) => Err(HolidayError::NotEveryYear(self.recurrence_desc())),
+            Offset { base, .. } | Substitute { base, .. } | WeekdayAfter { base, .. } => base.validate(),
+            FixedDate(_)
+            | NthDate(_)
+            | WeekdayRelative { .. }
+            | FromYearEnd { .. }
+            | Hijri { .. }
+            | NthWeekdayAbsolute { .. }
+            | LastBusinessDay { .. }
+            | DayOfYear { .. }
+            | EasterRelative { .. }
+            | WeeklySeries { .. }
+            | NearestWeekday { .. } => Ok(()),
+        }
+    }
+
+    /// The number of days from a given date until the next occurrence of the HolidayDate,
+    /// including the given date. Fixed dates are resolved directly from the month and day without
+    /// searching day by day; other date rules fall back to `after`. Panics if there is no
+    /// occurrence in the representable date range, e.g., for February 30.
+    pub fn days_until_fast(&self, from: NaiveDate) -> i64 {
+        match self {
+            FixedDate(day_of_month) => (from.year()..=NaiveDate::MAX.year())
+                .filter_map(|year| day_of_month.to_date(year))
+                .find(|date| *date >= from)
+                .map(|date| (date - from).num_days())
+                .expect("no occurrence after date"),
+            _ => (self.after(&from) - from).num_days(),
+        }
+    }
+
+    /// Returns every date in a given year on which the HolidayDate occurs. Most date rules occur
+    /// once a year, but some may not occur at all (February 29) and lunar dates may occur twice.
+    pub fn occurrences_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        match self {
+            Hijri { .. } if !self.supports_year(year) => Vec::new(),
+            Hijri { month, day } => hijri::occurrences_in_year(*month, *day, year),
+            WeeklySeries { .. } => (year - 1..=year + 1)
+                .flat_map(|y| self.weekly_series_in_year(y))
+                .filter(|date| date.year() == year)
+                .collect(),
+            _ => (year - 1..=year + 1)
+                .filter_map(|y| self.resolve_in_year(y))
+                .filter(|date| date.year() == year)
+                .collect(),
+        }
+    }
+
+    /// The dates of a WeeklySeries anchored to a given year in chronological order, or no dates
+    /// for any other date rule
+    fn weekly_series_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let (weekday, count, anchor, direction) = match self {
+            WeeklySeries { weekday, count, anchor, direction } => (weekday, *count as i64, anchor, direction),
+            _ => return Vec::new(),
+        };
+        let anchor = match anchor.to_date(year) {
+            Some(anchor) => anchor,
+            None => return Vec::new(),
+        };
+
+        let start = match direction {
+            SeriesDirection::Before => {
+                let days_behind = (anchor.weekday().num_days_from_sunday() + 6 - weekday.num_days_from_sunday()) % 7 + 1;
+                anchor - chrono::Duration::days(days_behind as i64) - chrono::Duration::weeks(count - 1)
+            }
+            SeriesDirection::After => {
+                let days_ahead = (weekday.num_days_from_sunday() + 6 - anchor.weekday().num_days_from_sunday()) % 7 + 1;
+                anchor + chrono::Duration::days(days_ahead as i64)
+            }
+        };
+        (0..count).map(|week| start + chrono::Duration::weeks(week)).collect()
+    }
+
+    /// Determine the date of the HolidayDate anchored to a given year, if it exists that year.
+    /// Some date rules may resolve to a date in the year before or after the given year.
+    pub(crate) fn resolve_in_year(&self, year: i32) -> Option<NaiveDate> {
+        match self {
+            EasterRelative { .. } | Hijri { .. } if !self.supports_year(year) => None,
+            FixedDate(day_of_month) => day_of_month.to_date(year),
+            NthDate(nth) => nth.to_date(year),
+            WeekdayRelative { weekday, anchor, count } => {
+                let anchor = anchor.to_date(year)?;
+                let mut date = anchor;
+                let mut found = 0;
+                while found < count.abs().max(1) {
+                    date = if *count < 0 { date.pred_opt()? } else { date.succ_opt()? };
+                    if date.weekday() == *weekday {
+                        found += 1;
+                    }
+                }
+                Some(date)
+            }
+            NthWeekdayAbsolute { count, weekday, from_month } => {
+                let first = NaiveDate::from_ymd_opt(year, *from_month as u32, 1)?;
+                let days_ahead = (weekday.num_days_from_sunday() + 7 - first.weekday().num_days_from_sunday()) % 7;
+                let weeks = (*count).max(1) - 1;
+                first.checked_add_signed(chrono::Duration::days(days_ahead as i64 + weeks as i64 * 7))
+            }
+            Offset { base, days } => base
+                .resolve_in_year(year)?
+                .checked_add_signed(chrono::Duration::days(*days as i64)),
+            FromYearEnd { offset_days } => NaiveDate::from_ymd_opt(year, 12, 31)?
+                .checked_sub_signed(chrono::Duration::days(*offset_days as i64)),
+            Hijri { .. } => self.occurrences_in_year(year).into_iter().next(),
+            Substitute { base, avoid, shift } => {
+                let mut date = shift.observe(base.resolve_in_year(year)?);
+                while avoid.iter().any(|other| other == &date) {
+                    date = date.succ_opt()?;
+                    while *shift != WeekendShift::NoShift && date.is_weekend() {
+                        date = date.succ_opt()?;
+                    }
+                }
+                Some(date)
+            }
+            LastBusinessDay { month } => {
+                let mut date = NaiveDate::from_ymd_opt(year, *month as u32, 1)?.last_day_of_month();
+                while date.is_weekend() {
+                    date = date.pred_opt()?;
+                }
+                Some(date)
+            }
+            DayOfYear { ordinal } => NaiveDate::from_yo_opt(year, *ordinal),
+            EasterRelative { days } => {
+                easter::easter_sunday(year)?.checked_add_signed(chrono::Duration::days(*days as i64))
+            }
+            WeeklySeries { .. } => self.weekly_series_in_year(year).into_iter().next(),
+            Table(entries) => entries
+                .iter()
+                .find(|(entry_year, _month, _day)| *entry_year == year)
+                .and_then(|(year, month, day)| NaiveDate::from_ymd_opt(*year, *month, *day)),
+            NearestWeekday { anchor } => {
+                let date = anchor.to_date(year)?;
+                match date.weekday() {
+                    Weekday::Sat => date.pred_opt(),
+                    Weekday::Sun => date.succ_opt(),
+                    _ => Some(date),
+                }
+            }
+            WeekdayAfter { base, weekday } => {
+                let date = base.resolve_in_year(year)?;
+                let days_ahead = (weekday.num_days_from_sunday() + 7 - date.weekday().num_days_from_sunday()) % 7;
+                date.checked_add_signed(chrono::Duration::days(days_ahead as i64))
+            }
+        }
+    }
+}
+
+impl From<DayOfMonth> for HolidayDate {
+    fn from(day_of_month: DayOfMonth) -> Self {
+        FixedDate(day_of_month)
+    }
+}
+
+impl From<NthWeekdayOfMonth> for HolidayDate {
+    fn from(nth: NthWeekdayOfMonth) -> Self {
+        NthDate(nth)
+    }
+}
+
+impl<S> AsRef<HolidayDate> for Holiday<S> {
+    fn as_ref(&self) -> &HolidayDate {
+        &self.date
+    }
 }
 
 /// A fixed day of the month (e.g.:  March 31)
@@ -125,6 +1148,32 @@ impl DayOfMonth {
     pub fn iter(&self) -> HolidayIter<Self> {
         self.into_iter()
     }
+
+    /// Shift the DayOfMonth by a number of days, if the result is the same in leap years and
+    /// common years
+    fn shift_by(self, days: i32) -> Option<Self> {
+        let shift = |year| {
+            self.to_date(year)?
+                .checked_add_signed(chrono::Duration::days(days as i64))
+                .map(|date| DayOfMonth::new(date.day(), date.month()))
+        };
+
+        match (shift(2000), shift(2001)) {
+            (Some(leap), Some(common)) if leap == common => Some(leap),
+            _ => None,
+        }
+    }
+
+    /// The date of the DayOfMonth in a given year, if it exists that year
+    pub(crate) fn to_date(self, year: i32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, self.month as u32, self.day)
+    }
+}
+
+impl From<(Month, u32)> for DayOfMonth {
+    fn from((month, day): (Month, u32)) -> Self {
+        DayOfMonth::new(day, month)
+    }
 }
 
 /// Nth weekday of a month (e.g.: Second Tuesday in October)
@@ -149,6 +1198,70 @@ impl NthWeekdayOfMonth {
     pub fn iter(&self) -> HolidayIter<Self> {
         self.into_iter()
     }
+
+    /// Compare two NthWeekdayOfMonths by month, then nth, then weekday, counting weekdays from
+    /// the given start of the week
+    pub fn cmp_with_week_start(&self, other: &Self, week_start: WeekStart) -> std::cmp::Ordering {
+        self.month
+            .cmp(&other.month)
+            .then(self.nth.cmp(&other.nth))
+            .then(week_start.days_from(self.weekday).cmp(&week_start.days_from(other.weekday)))
+    }
+
+    /// Compare two NthWeekdayOfMonths by the dates they resolve to in a given year. Those that do
+    /// not occur that year, e.g., a 5th Friday in a month with only four, sort last.
+    pub fn cmp_resolved(&self, other: &Self, year: i32) -> std::cmp::Ordering {
+        let resolved = |nth: &Self| {
+            let date = nth.to_date(year);
+            (date.is_none(), date)
+        };
+        resolved(self)
+            .cmp(&resolved(other))
+            .then_with(|| self.cmp(other))
+    }
+
+    /// Explain how the NthWeekdayOfMonth resolves in a given year.
+    /// Example: "The 4th Thursday of November 2021 is the 25th (first Thursday was the 4th)"
+    pub fn explain(&self, year: i32) -> String {
+        let nth = match self.nth {
+            NthWeekday::Last => "last".to_string(),
+            nth => ordinal(nth as u32),
+        };
+        let weekday = weekday_name(self.weekday);
+        let first = NthWeekdayOfMonth { nth: First, ..*self }.to_date(year);
+
+        match (self.to_date(year), first) {
+            (Some(date), Some(first)) => format!(
+                "The {} {} of {:?} {} is the {} (first {} was the {})",
+                nth, weekday, self.month, year, ordinal(date.day()), weekday, ordinal(first.day()),
+            ),
+            (None, Some(first)) => format!(
+                "There is no {} {} of {:?} {} (first {} was the {})",
+                nth, weekday, self.month, year, weekday, ordinal(first.day()),
+            ),
+            (_, None) => format!("There is no {} {} of {:?} {}", nth, weekday, self.month, year),
+        }
+    }
+
+    /// The date of the NthWeekdayOfMonth in a given year, or `None` if it does not exist that
+    /// year, e.g., a 5th Friday in a month with only four
+    pub fn to_date(&self, year: i32) -> Option<NaiveDate> {
+        let weekday = self.weekday.num_days_from_sunday();
+        let first = NaiveDate::from_ymd_opt(year, self.month as u32, 1)?;
+        let first_offset = (weekday + 7 - first.weekday().num_days_from_sunday()) % 7;
+
+        if self.nth == NthWeekday::Last {
+            let days_in_month = (28..=31)
+                .rev()
+                .find(|day| first.with_day(*day).is_some())
+                .expect("invalid month");
+            let fifth = 1 + first_offset + 28;
+            let day = if fifth <= days_in_month { fifth } else { fifth - 7 };
+            first.with_day(day)
+        } else {
+            first.with_day(1 + first_offset + 7 * (self.nth as u32 - 1))
+        }
+    }
 }
 
 impl From<NaiveDate> for NthWeekdayOfMonth {
@@ -176,6 +1289,57 @@ impl From<NaiveDate> for NthWeekdayOfMonth {
     }
 }
 
+impl From<(NthWeekday, Weekday, Month)> for NthWeekdayOfMonth {
+    fn from((nth, weekday, month): (NthWeekday, Weekday, Month)) -> Self {
+        NthWeekdayOfMonth::new(nth, weekday, month)
+    }
+}
+
+/// The first day of the week, used to order weekdays
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum WeekStart {
+    /// Weeks start on Sunday
+    #[default]
+    Sunday,
+    /// Weeks start on Monday
+    Monday,
+}
+
+impl WeekStart {
+    /// The number of days from the start of the week to a given weekday
+    pub fn days_from(&self, weekday: Weekday) -> u32 {
+        match self {
+            WeekStart::Sunday => weekday.num_days_from_sunday(),
+            WeekStart::Monday => weekday.num_days_from_monday(),
+        }
+    }
+}
+
+/// Format a number as an English ordinal: 1st, 2nd, 3rd, 4th...
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// The full English name of a weekday
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
 /// The nth ocurrence of a weekday in a month.
 ///
 /// Using the `Fifth` explicitly may panic if you try
@@ -235,6 +1399,15 @@ impl Month {
     pub fn from_zero(&self) -> u32 {
         *self as u32 - 1
     }
+
+    /// The most days the month can have, counting February 29 in leap years
+    pub const fn max_days(self) -> u32 {
+        match self {
+            February => 29,
+            April | June | September | November => 30,
+            _ => 31,
+        }
+    }
 }
 
 impl From<u32> for Month {
@@ -272,3 +1445,422 @@ fn tgives_nth_weekday_of_month() {
 
     dbg!(NthWeekdayOfMonth::from(NaiveDate::from_ymd(2020, 6, 8)));
 }
+
+#[test]
+fn nth_weekday_of_month_week_start() {
+    use std::cmp::Ordering;
+    let sunday = NthWeekdayOfMonth::new(First, Weekday::Sun, May);
+    let monday = NthWeekdayOfMonth::new(First, Weekday::Mon, May);
+
+    assert_eq!(sunday.cmp(&monday), Ordering::Less);
+    assert_eq!(sunday.cmp_with_week_start(&monday, WeekStart::Sunday), Ordering::Less);
+    assert_eq!(sunday.cmp_with_week_start(&monday, WeekStart::Monday), Ordering::Greater);
+}
+
+#[test]
+fn nth_weekday_of_month_cmp_resolved() {
+    let mut nths = vec![
+        NthWeekdayOfMonth::new(First, Weekday::Sun, May),
+        NthWeekdayOfMonth::new(First, Weekday::Mon, May),
+        NthWeekdayOfMonth::new(First, Weekday::Sat, May),
+    ];
+
+    nths.sort_by(|a, b| a.cmp_resolved(b, 2021));
+    assert_eq!(nths, vec![
+        NthWeekdayOfMonth::new(First, Weekday::Sat, May),
+        NthWeekdayOfMonth::new(First, Weekday::Sun, May),
+        NthWeekdayOfMonth::new(First, Weekday::Mon, May),
+    ]);
+
+    // There is no 5th Friday in May 2021
+    let fifth_friday = NthWeekdayOfMonth::new(Fifth, Weekday::Fri, May);
+    let last_friday = NthWeekdayOfMonth::new(Last, Weekday::Fri, May);
+    assert_eq!(fifth_friday.cmp_resolved(&last_friday, 2021), std::cmp::Ordering::Greater);
+}
+
+#[test]
+fn nth_weekday_of_month_explain() {
+    let tgives = NthWeekdayOfMonth::new(Fourth, Weekday::Thu, November);
+    assert_eq!(
+        tgives.explain(2021),
+        "The 4th Thursday of November 2021 is the 25th (first Thursday was the 4th)"
+    );
+
+    let fifth = NthWeekdayOfMonth::new(Fifth, Weekday::Wed, December);
+    assert_eq!(
+        fifth.explain(2019),
+        "There is no 5th Wednesday of December 2019 (first Wednesday was the 4th)"
+    );
+
+    assert_eq!(tgives.explain(i32::MAX), format!("There is no 4th Thursday of November {}", i32::MAX));
+}
+
+#[test]
+fn holiday_with_name() {
+    use holidays::united_states::COLUMBUS_DAY;
+    let indigenous = COLUMBUS_DAY.with_name("Indigenous Peoples' Day".to_string());
+    assert_eq!(indigenous.name(), "Indigenous Peoples' Day");
+    assert!(indigenous.same_date(&COLUMBUS_DAY));
+    assert_eq!(indigenous.in_year(2021), COLUMBUS_DAY.in_year(2021));
+    assert!(!indigenous.same_date(&holidays::united_states::VETERANS_DAY));
+}
+
+#[test]
+fn days_until_fast_matches_after() {
+    use holidays::{global::*, united_states::*};
+    let holidays = [NEW_YEARS_DAY, LEAP_DAY, INDEPENDENCE_DAY, THANKSGIVING, CHRISTMAS, NEW_YEARS_EVE];
+    let mut from = NaiveDate::from_ymd(1999, 1, 1);
+    while from < NaiveDate::from_ymd(2005, 1, 1) {
+        for holiday in holidays.iter() {
+            assert_eq!(holiday.days_until_fast(from), (holiday.after(&from) - from).num_days());
+        }
+        from = from.succ();
+    }
+}
+
+#[test]
+#[should_panic(expected = "no occurrence after date")]
+fn days_until_fast_never_occurs() {
+    Holiday::new_fixed("February 30", February, 30).days_until_fast(NaiveDate::from_ymd(2021, 1, 1));
+}
+
+#[test]
+fn holiday_validate() {
+    use holidays::{global::*, united_states::*};
+    assert_eq!(LEAP_DAY.validate(), Err(HolidayError::NotEveryYear("Leap Day".to_string())));
+
+    let fifth = holiday!("Fifth Wednesday in December", Fifth, Weekday::Wed, December);
+    assert_eq!(fifth.validate(), Err(HolidayError::NotEveryYear("Fifth Wednesday in December".to_string())));
+    assert!(fifth.shift_by(1).validate().is_err());
+
+    assert_eq!(THANKSGIVING.validate(), Ok(()));
+    assert_eq!(MEMORIAL_DAY.validate(), Ok(()));
+    assert_eq!(CHRISTMAS.validate(), Ok(()));
+
+    let series = HolidayDate::WeeklySeries {
+        weekday: Weekday::Sun,
+        count: 2,
+        anchor: DayOfMonth::new(29, February),
+        direction: SeriesDirection::Before,
+    };
+    assert!(series.validate().is_err());
+    let relative = HolidayDate::WeekdayRelative { weekday: Weekday::Mon, anchor: DayOfMonth::new(29, February), count: 1 };
+    assert!(relative.validate().is_err());
+
+    let olympics = Holiday::new_fixed("Olympics", July, 23).with_recurrence(Recurrence::EveryNYears(4));
+    assert_eq!(olympics.validate(), Err(HolidayError::NotEveryYear("Olympics".to_string())));
+    assert_eq!(CHRISTMAS.with_recurrence(Recurrence::EveryNYears(1)).validate(), Ok(()));
+}
+
+#[test]
+fn nth_weekday_absolute() {
+    let sixth = HolidayDate::NthWeekdayAbsolute { count: 6, weekday: Weekday::Mon, from_month: January };
+    assert_eq!(sixth.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 2, 8));
+
+    let eighth = HolidayDate::NthWeekdayAbsolute { count: 8, weekday: Weekday::Mon, from_month: January };
+    assert_eq!(eighth.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 2, 22));
+    assert_eq!(eighth, NaiveDate::from_ymd(2021, 2, 22));
+    assert_eq!(eighth.recurrence_desc(), "abs 8 Mon from 1");
+
+    let first = HolidayDate::NthWeekdayAbsolute { count: 1, weekday: Weekday::Fri, from_month: January };
+    assert_eq!(first.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 1, 1));
+}
+
+#[test]
+fn holiday_date_conversions() {
+    fn first_after<D: AsRef<HolidayDate>>(date: D, from: NaiveDate) -> NaiveDate {
+        date.as_ref().after(&from)
+    }
+
+    let from = NaiveDate::from_ymd(2021, 1, 1);
+    let christmas: HolidayDate = DayOfMonth::new(25, December).into();
+    assert_eq!(christmas, HolidayDate::FixedDate(DayOfMonth::new(25, December)));
+    assert_eq!(Holiday::new("Christmas", DayOfMonth::new(25, December).into()), holidays::global::CHRISTMAS);
+
+    let tgives: HolidayDate = NthWeekdayOfMonth::new(Fourth, Weekday::Thu, November).into();
+    assert_eq!(first_after(&holidays::united_states::THANKSGIVING, from), tgives.after(&from));
+    assert_eq!(first_after(holidays::global::CHRISTMAS, from), NaiveDate::from_ymd(2021, 12, 25));
+}
+
+#[test]
+fn fixed_observed() {
+    let independence = Holiday::new_fixed_observed("Independence Day (Observed)", July, 4, WeekendShift::UsFederal);
+    assert_eq!(independence.in_year(2021), NaiveDate::from_ymd(2021, 7, 5));
+    assert_eq!(independence.in_year(2026), NaiveDate::from_ymd(2026, 7, 3));
+    assert_eq!(independence.in_year(2024), NaiveDate::from_ymd(2024, 7, 4));
+
+    let new_years = Holiday::new_fixed_observed("New Year's Day (Observed)", January, 1, WeekendShift::UsFederal);
+    assert_eq!(new_years.occurrences_in_year(2021), vec![
+        NaiveDate::from_ymd(2021, 1, 1),
+        NaiveDate::from_ymd(2021, 12, 31),
+    ]);
+}
+
+#[test]
+fn weekday_distribution() {
+    use holidays::{global::CHRISTMAS, united_states::THANKSGIVING};
+    // The Gregorian calendar repeats every 28 years between 1901 and 2099
+    assert_eq!(CHRISTMAS.weekday_distribution(2001, 2028), [4; 7]);
+    assert_eq!(THANKSGIVING.weekday_distribution(2001, 2028), [0, 0, 0, 28, 0, 0, 0]);
+    assert_eq!(CHRISTMAS.weekday_distribution(2021, 2021), [0, 0, 0, 0, 0, 1, 0]);
+}
+
+#[test]
+fn last_business_day() {
+    let may = HolidayDate::LastBusinessDay { month: May };
+    assert_eq!(may.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 5, 31));
+
+    let january = HolidayDate::LastBusinessDay { month: January };
+    assert_eq!(january.after(&NaiveDate::from_ymd(2022, 1, 1)), NaiveDate::from_ymd(2022, 1, 31));
+
+    // July 31, 2021 is a Saturday
+    let july = HolidayDate::LastBusinessDay { month: July };
+    assert_eq!(july.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 7, 30));
+    assert_eq!(july, NaiveDate::from_ymd(2021, 7, 30));
+    assert!(july != NaiveDate::from_ymd(2021, 7, 31));
+}
+
+#[test]
+fn day_of_year() {
+    let first = HolidayDate::DayOfYear { ordinal: 1 };
+    assert_eq!(first.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 1, 1));
+
+    let sixtieth = HolidayDate::DayOfYear { ordinal: 60 };
+    assert_eq!(sixtieth.after(&NaiveDate::from_ymd(2020, 1, 1)), NaiveDate::from_ymd(2020, 2, 29));
+    assert_eq!(sixtieth.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2021, 3, 1));
+
+    let last = HolidayDate::DayOfYear { ordinal: 366 };
+    assert_eq!(last.occurrences_in_year(2021), vec![]);
+    assert_eq!(last.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2024, 12, 31));
+    assert!(last.validate().is_err());
+}
+
+#[test]
+fn holiday_datetime_cmp() {
+    let fireworks = Holiday::new_fixed("Fireworks", July, 4).with_time(NaiveTime::from_hms(20, 0, 0));
+    let morning = NaiveDate::from_ymd(2021, 7, 4).and_hms(9, 0, 0);
+    let evening = NaiveDate::from_ymd(2021, 7, 4).and_hms(19, 0, 0);
+    let start = NaiveDate::from_ymd(2021, 7, 4).and_hms(20, 0, 0);
+    let late = NaiveDate::from_ymd(2021, 7, 4).and_hms(22, 30, 0);
+
+    assert_eq!(fireworks.datetime_in_year(2021), start);
+    assert_eq!(fireworks, start);
+    assert_ne!(fireworks, evening);
+    assert!(fireworks > morning);
+    assert!(fireworks > evening);
+    assert!(fireworks < late);
+    assert!(fireworks > NaiveDate::from_ymd(2021, 7, 3).and_hms(23, 0, 0));
+
+    assert_eq!(holidays::global::CHRISTMAS, NaiveDate::from_ymd(2021, 12, 25).and_hms(0, 0, 0));
+    assert!(holidays::global::CHRISTMAS < NaiveDate::from_ymd(2021, 12, 25).and_hms(8, 0, 0));
+}
+
+#[test]
+fn month_max_days() {
+    assert_eq!(February.max_days(), 29);
+    assert_eq!(April.max_days(), 30);
+    assert_eq!(December.max_days(), 31);
+}
+
+#[test]
+fn surrounding_weekend() {
+    use holidays::{global::{CHRISTMAS, LEAP_DAY}, united_states::*};
+    let weekend = |(y1, m1, d1), (y2, m2, d2)| Some((NaiveDate::from_ymd(y1, m1, d1), NaiveDate::from_ymd(y2, m2, d2)));
+    assert_eq!(MEMORIAL_DAY.surrounding_weekend(2021), weekend((2021, 5, 29), (2021, 5, 31)));
+    assert_eq!(INDEPENDENCE_DAY.surrounding_weekend(2025), weekend((2025, 7, 4), (2025, 7, 6)));
+    assert_eq!(CHRISTMAS.surrounding_weekend(2021), weekend((2021, 12, 25), (2021, 12, 26)));
+    assert_eq!(INDEPENDENCE_DAY.surrounding_weekend(2023), weekend((2023, 7, 1), (2023, 7, 4)));
+    assert_eq!(INDEPENDENCE_DAY.surrounding_weekend(2018), weekend((2018, 6, 30), (2018, 7, 4)));
+    assert_eq!(THANKSGIVING.surrounding_weekend(2021), weekend((2021, 11, 25), (2021, 11, 28)));
+    assert_eq!(LEAP_DAY.surrounding_weekend(2021), None);
+}
+
+#[test]
+fn from_tuples() {
+    assert_eq!(NthWeekdayOfMonth::from((Fourth, Weekday::Thu, November)), NthWeekdayOfMonth::new(Fourth, Weekday::Thu, November));
+    assert_eq!(DayOfMonth::from((December, 25)), DayOfMonth::new(25, December));
+    assert_eq!(holidays::united_states::THANKSGIVING.date, HolidayDate::from(NthWeekdayOfMonth::from((Fourth, Weekday::Thu, November))));
+}
+
+#[test]
+fn days_since() {
+    use holidays::global::NEW_YEARS_DAY;
+    assert_eq!(NEW_YEARS_DAY.days_since(NaiveDate::from_ymd(2021, 1, 5)), 4);
+    assert_eq!(NEW_YEARS_DAY.days_since(NaiveDate::from_ymd(2021, 1, 1)), 366);
+}
+
+#[test]
+fn nth_weekday_to_date() {
+    let thanksgiving = NthWeekdayOfMonth::new(Fourth, Weekday::Thu, November);
+    assert_eq!(thanksgiving.to_date(2021), Some(NaiveDate::from_ymd(2021, 11, 25)));
+
+    let fifth_friday = NthWeekdayOfMonth::new(Fifth, Weekday::Fri, February);
+    assert_eq!(fifth_friday.to_date(2021), None);
+    assert_eq!(fifth_friday.to_date(2008), Some(NaiveDate::from_ymd(2008, 2, 29)));
+}
+
+#[test]
+fn monthly_recurrence() {
+    let payday = Holiday::new_fixed("Payday", January, 15).with_recurrence(Recurrence::Monthly);
+    let paydays = payday.occurrences_in_year(2021);
+    assert_eq!(paydays.len(), 12);
+    assert_eq!(paydays[1], NaiveDate::from_ymd(2021, 2, 15));
+    assert_eq!(payday.after(&NaiveDate::from_ymd(2021, 3, 16)), NaiveDate::from_ymd(2021, 4, 15));
+    assert_eq!(payday.before(&NaiveDate::from_ymd(2021, 1, 15)), NaiveDate::from_ymd(2020, 12, 15));
+    assert_eq!(payday, NaiveDate::from_ymd(2021, 7, 15));
+
+    let all_hands = Holiday::new_nth("All Hands", First, Weekday::Fri, January).with_recurrence(Recurrence::Monthly);
+    assert_eq!(all_hands.iter().at(NaiveDate::from_ymd(2021, 5, 2)).next(), Some(NaiveDate::from_ymd(2021, 5, 7)));
+
+    let month_end = Holiday::new_fixed("Month End", January, 31).with_recurrence(Recurrence::Monthly);
+    assert_eq!(month_end.occurrences_in_year(2021).len(), 7);
+}
+
+#[test]
+fn every_n_years_recurrence() {
+    let census = Holiday::new_fixed("Census Day", April, 1)
+        .with_valid_from(1930)
+        .with_recurrence(Recurrence::EveryNYears(10));
+    assert_eq!(census.after(&NaiveDate::from_ymd(2021, 1, 1)), NaiveDate::from_ymd(2030, 4, 1));
+    assert!(census.occurrences_in_year(2025).is_empty());
+    assert_eq!(census, NaiveDate::from_ymd(2020, 4, 1));
+    assert_ne!(census, NaiveDate::from_ymd(2021, 4, 1));
+}
+
+#[test]
+fn sort_holidays_by_year() {
+    use holidays::{global::*, united_states::*};
+    let mut all = holidays::global::ALL
+        .iter()
+        .chain(holidays::united_states::ALL)
+        .cloned()
+        .collect::<Vec<_>>();
+    sort_by_year(&mut all, 2021);
+
+    let dates = all.iter().map(|holiday| holiday.in_year(2021)).collect::<Vec<_>>();
+    assert!(dates.windows(2).all(|pair| pair[0] <= pair[1]));
+
+    let position = |holiday: &Holiday<&str>| all.iter().position(|h| h == holiday).unwrap();
+    assert!(position(&APRIL_FOOLS_DAY) < position(&EASTER));
+    assert!(position(&EASTER) < position(&MOTHERS_DAY));
+    assert!(position(&HALLOWEEN) < position(&THANKSGIVING));
+}
+
+#[test]
+fn movable_holidays() {
+    use holidays::{global::*, united_states::*};
+    assert!(!CHRISTMAS.is_movable());
+    assert!(THANKSGIVING.is_movable());
+    assert!(EASTER.is_movable());
+    assert!(holidays::islamic::EID_AL_FITR.is_movable());
+    assert!(!CHRISTMAS.shift_by(1).is_movable());
+    assert!(THANKSGIVING.shift_by(1).is_movable());
+}
+
+#[test]
+fn nearest_weekday() {
+    let nearest = HolidayDate::NearestWeekday { anchor: DayOfMonth::new(4, July) };
+    // July 4, 2020 is a Saturday and July 4, 2021 is a Sunday
+    assert_eq!(nearest.resolve_in_year(2020), Some(NaiveDate::from_ymd(2020, 7, 3)));
+    assert_eq!(nearest.resolve_in_year(2021), Some(NaiveDate::from_ymd(2021, 7, 5)));
+    assert_eq!(nearest.resolve_in_year(2022), Some(NaiveDate::from_ymd(2022, 7, 4)));
+
+    let holiday = Holiday::new("Independence Day (observed)", nearest);
+    assert_eq!(holiday, NaiveDate::from_ymd(2021, 7, 5));
+    assert_eq!(holiday.date.recurrence_desc(), "nearest-weekday 07-04");
+}
+
+#[test]
+fn weekday_after() {
+    let monday_after_easter = HolidayDate::WeekdayAfter {
+        base: Box::new(EasterRelative { days: 0 }),
+        weekday: Weekday::Mon,
+    };
+    assert_eq!(monday_after_easter.resolve_in_year(2021), Some(NaiveDate::from_ymd(2021, 4, 5)));
+    assert_eq!(
+        monday_after_easter.resolve_in_year(2021),
+        holidays::global::EASTER_MONDAY.date.resolve_in_year(2021)
+    );
+
+    // The base date counts when it is already on the weekday
+    let sunday_after_easter = HolidayDate::WeekdayAfter {
+        base: Box::new(EasterRelative { days: 0 }),
+        weekday: Weekday::Sun,
+    };
+    assert_eq!(sunday_after_easter.resolve_in_year(2021), Some(NaiveDate::from_ymd(2021, 4, 4)));
+    assert_eq!(sunday_after_easter.recurrence_desc(), "(easter+0) onafter Sun");
+}
+
+#[test]
+fn holiday_around() {
+    use holidays::global::CHRISTMAS;
+    assert_eq!(
+        CHRISTMAS.around(2021, 1),
+        vec![
+            (2020, NaiveDate::from_ymd(2020, 12, 25)),
+            (2021, NaiveDate::from_ymd(2021, 12, 25)),
+            (2022, NaiveDate::from_ymd(2022, 12, 25)),
+        ]
+    );
+    assert_eq!(CHRISTMAS.around(2021, 0), vec![(2021, NaiveDate::from_ymd(2021, 12, 25))]);
+}
+
+#[test]
+fn try_in_year_supported_range() {
+    use holidays::{global::*, islamic::RAMADAN};
+    assert_eq!(EASTER.try_in_year(2021), Some(NaiveDate::from_ymd(2021, 4, 4)));
+    assert_eq!(EASTER.try_in_year(1583), Some(NaiveDate::from_ymd(1583, 4, 10)));
+    assert_eq!(EASTER.try_in_year(1582), None);
+    assert_eq!(GOOD_FRIDAY.try_in_year(1000), None);
+    assert_eq!(EASTER.date.min_supported_year(), Some(1583));
+    assert_eq!(EASTER.date.max_supported_year(), None);
+    assert_eq!(RAMADAN.try_in_year(600), None);
+    assert_eq!(CHRISTMAS.try_in_year(1000), Some(NaiveDate::from_ymd(1000, 12, 25)));
+    assert_eq!(LEAP_DAY.try_in_year(2021), None);
+
+    // The supported range applies to every method, not just try_in_year
+    assert_eq!(EASTER.first_date(), NaiveDate::from_ymd(1583, 4, 10));
+    assert_eq!(EASTER.in_year(1500), NaiveDate::from_ymd(1583, 4, 10));
+    assert_eq!(GOOD_FRIDAY.before(&NaiveDate::from_ymd(1583, 4, 10)), NaiveDate::from_ymd(1583, 4, 8));
+    assert!(EASTER.occurrences_in_year(1582).is_empty());
+    assert!(RAMADAN.occurrences_in_year(621).is_empty());
+    assert_eq!(RAMADAN.first_date().year(), 623);
+
+    let table = Holiday::new("Table", HolidayDate::Table(&[(2020, 5, 1), (2022, 5, 2)]));
+    assert_eq!(table.date.min_supported_year(), Some(2020));
+    assert_eq!(table.date.max_supported_year(), Some(2022));
+    assert_eq!(table.try_in_year(2023), None);
+}
+
+#[test]
+fn before_after_date_in_year() {
+    use holidays::united_states::THANKSGIVING;
+    let mid_december = NaiveDate::from_ymd(2021, 12, 15);
+    assert!(THANKSGIVING.is_before(&mid_december, 2021));
+    assert!(!THANKSGIVING.is_after(&mid_december, 2021));
+    assert!(THANKSGIVING.is_after(&mid_december, 2022));
+
+    let thanksgiving = NaiveDate::from_ymd(2021, 11, 25);
+    assert!(!THANKSGIVING.is_before(&thanksgiving, 2021));
+    assert!(!THANKSGIVING.is_after(&thanksgiving, 2021));
+}
+
+#[test]
+fn weekday_cycle() {
+    use holidays::global::{CHRISTMAS, LEAP_DAY};
+    let cycle = CHRISTMAS.weekday_cycle();
+    assert_eq!(cycle.iter().sum::<u32>(), 400);
+    assert_eq!(cycle, [56, 58, 57, 57, 58, 56, 58]);
+    assert_eq!(LEAP_DAY.weekday_cycle().iter().sum::<u32>(), 97);
+}
+
+#[test]
+fn new_fixed_checked() {
+    assert_eq!(Holiday::new_fixed_checked("Invalid", April, 31), Err(HolidayError::InvalidInput("April 31".into())));
+    assert!(Holiday::new_fixed_checked("Invalid", February, 30).is_err());
+    assert!(Holiday::new_fixed_checked("Invalid", January, 0).is_err());
+
+    let leap_day = Holiday::new_fixed_checked("Leap Day", February, 29).unwrap();
+    assert_eq!(leap_day, Holiday::new_fixed("Leap Day", February, 29));
+    assert_eq!(Holiday::new_fixed_checked("Halloween", 10, 31).unwrap().in_year(2021), NaiveDate::from_ymd(2021, 10, 31));
+}
@@ -0,0 +1,350 @@
+//! # Observed holidays
+//!
+//! Holidays that fall on a weekend are often observed on a nearby weekday instead
+
+use crate::*;
+
+use std::collections::BTreeSet;
+
+/// How a holiday that falls on a weekend is shifted to the day it is observed
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub enum WeekendShift {
+    /// The holiday is observed on its actual date
+    #[default]
+    NoShift,
+    /// Saturday holidays are observed the Friday before, Sunday holidays the Monday after
+    UsFederal,
+    /// Saturday and Sunday holidays are observed the Monday after
+    NextMonday,
+}
+
+impl WeekendShift {
+    /// Determine the observed date of a holiday falling on a given date. A holiday whose observed
+    /// date would fall outside the range `NaiveDate` supports is observed on the date itself.
+    pub fn observe(&self, date: NaiveDate) -> NaiveDate {
+        let days = match (self, date.weekday()) {
+            (WeekendShift::UsFederal, Weekday::Sat) => -1,
+            (WeekendShift::UsFederal, Weekday::Sun) => 1,
+            (WeekendShift::NextMonday, Weekday::Sat) => 2,
+            (WeekendShift::NextMonday, Weekday::Sun) => 1,
+            _ => 0,
+        };
+        date.checked_add_signed(chrono::Duration::days(days)).unwrap_or(date)
+    }
+}
+
+/// Whether a Holiday falls or is observed on a given day
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TodayStatus {
+    /// The Holiday falls on the day
+    Today,
+    /// The Holiday falls on another day but is observed on the day
+    ObservedToday,
+    /// The Holiday neither falls nor is observed on the day
+    NotToday,
+}
+
+impl<S: ToString> Holiday<S> {
+    /// Determine the observed date of a Holiday in a given year. The observed date may fall in the
+    /// previous or next year, e.g., New Year's Day on a Saturday observed on December 31.
+    pub fn observed_in_year(&self, year: i32, shift: WeekendShift) -> NaiveDate {
+        shift.observe(self.in_year(year))
+    }
+
+    /// Determine whether a Holiday falls or is observed today
+    pub fn today_status(&self, shift: WeekendShift) -> TodayStatus {
+        self.status_on(&Local::now().date_naive(), shift)
+    }
+
+    /// Determine whether a Holiday falls or is observed on a given date. A Holiday that falls on
+    /// the date is `Today` even if it is observed on another day.
+    pub fn status_on(&self, date: &NaiveDate, shift: WeekendShift) -> TodayStatus {
+        if self.occurrences_in_year(date.year()).contains(date) {
+            TodayStatus::Today
+        } else if observed_occurrences_in_year(self, date.year(), shift).contains(date) {
+            TodayStatus::ObservedToday
+        } else {
+            TodayStatus::NotToday
+        }
+    }
+
+    /// Determine if a Holiday falls or is observed on a given date
+    pub fn eq_observed(&self, date: &NaiveDate, shift: WeekendShift) -> bool {
+        self.status_on(date, shift) != TodayStatus::NotToday
+    }
+}
+
+impl<S: ToString> HolidayCalendar<S> {
+    /// Returns every distinct observed date of the Holidays in the calendar that falls in a given
+    /// year. Holidays observed on the same date are only counted once.
+    pub fn days_off_in_year(&self, year: i32, shift: WeekendShift) -> BTreeSet<NaiveDate> {
+        self.holidays()
+            .iter()
+            .flat_map(|holiday| observed_occurrences_in_year(holiday, year, shift))
+            .collect()
+    }
+
+    /// Count the distinct observed dates of the Holidays in the calendar that fall on a weekday in
+    /// a given year. Holidays that fall on a weekend only count if they are shifted to a weekday.
+    pub fn total_days_off(&self, year: i32, shift: WeekendShift) -> u32 {
+        self.days_off_in_year(year, shift)
+            .iter()
+            .filter(|date| !date.is_weekend())
+            .count() as u32
+    }
+
+    /// Returns the first and last dates (inclusive) of every stretch of three or more consecutive
+    /// days off, counting weekends and observed Holidays, with a day in a given year. A stretch
+    /// over the new year, like Saturday, December 30 through Monday, January 1, is returned in
+    /// full for both years.
+    pub fn long_weekends(&self, year: i32, shift: WeekendShift) -> Vec<(NaiveDate, NaiveDate)> {
+        self.stretches_off(year, shift)
+            .into_iter()
+            .filter(|(start, end, _days_off)| (*end - *start).num_days() >= 2)
+            .map(|(start, end, _days_off)| (start, end))
+            .collect()
+    }
+
+    /// Returns the first and last dates (inclusive) of every closure with a day in a given year:
+    /// a stretch of consecutive observed Holidays, merged with any adjacent weekends. Unlike
+    /// `long_weekends`, a single observed Holiday is a closure, and a weekend without a Holiday is
+    /// not.
+    pub fn closures_in_year(&self, year: i32, shift: WeekendShift) -> Vec<(NaiveDate, NaiveDate)> {
+        self.stretches_off(year, shift)
+            .into_iter()
+            .filter(|(_start, _end, days_off)| *days_off > 0)
+            .map(|(start, end, _days_off)| (start, end))
+            .collect()
+    }
+
+    /// The first and last dates (inclusive) of every stretch of consecutive weekends and observed
+    /// Holidays with a day in a given year, and the number of observed Holidays in each
+    fn stretches_off(&self, year: i32, shift: WeekendShift) -> Vec<(NaiveDate, NaiveDate, u32)> {
+        let days_off = (year - 1..=year + 1)
+            .flat_map(|y| self.days_off_in_year(y, shift))
+            .collect::<BTreeSet<_>>();
+        let (first_day, last_day) = match (NaiveDate::from_ymd_opt(year, 1, 1), NaiveDate::from_ymd_opt(year, 12, 31)) {
+            (Some(first_day), Some(last_day)) => (first_day, last_day),
+            _ => return Vec::new(),
+        };
+        let search_start = first_day.checked_sub_signed(chrono::Duration::days(7)).unwrap_or(NaiveDate::MIN);
+        let search_end = last_day.checked_add_signed(chrono::Duration::days(7)).unwrap_or(NaiveDate::MAX);
+
+        let mut stretches = Vec::new();
+        let mut stretch: Option<(NaiveDate, NaiveDate, u32)> = None;
+        for date in search_start.iter_days().take_while(|date| *date <= search_end) {
+            if date.is_weekend() || days_off.contains(&date) {
+                let (start, count) = stretch.map_or((date, 0), |(start, _end, count)| (start, count));
+                stretch = Some((start, date, count + days_off.contains(&date) as u32));
+            } else if let Some((start, end, count)) = stretch.take() {
+                if end >= first_day && start <= last_day {
+                    stretches.push((start, end, count));
+                }
+            }
+        }
+        stretches
+    }
+
+    /// Count the weekdays (Monday through Friday) in a given year that are not observed Holidays
+    pub fn working_days_in_year(&self, year: i32, shift: WeekendShift) -> u32 {
+        let weekdays = NaiveDate::from_ymd_opt(year, 1, 1)
+            .into_iter()
+            .flat_map(|first_day| first_day.iter_days())
+            .take_while(|date| date.year() == year)
+            .filter(|date| !date.is_weekend())
+            .count() as u32;
+        weekdays.saturating_sub(self.total_days_off(year, shift))
+    }
+}
+
+/// Every observed date of a Holiday that falls in a given year, including occurrences in the
+/// previous or next year that are observed in the given year
+fn observed_occurrences_in_year<S: ToString>(holiday: &Holiday<S>, year: i32, shift: WeekendShift) -> Vec<NaiveDate> {
+    (year - 1..=year + 1)
+        .flat_map(|y| holiday.occurrences_in_year(y))
+        .map(|date| shift.observe(date))
+        .filter(|date| date.year() == year)
+        .collect()
+}
+
+/// A HolidayCalendar that resolves every Holiday to its observed date using a `WeekendShift`
+#[derive(Debug, Clone)]
+pub struct ObservedCalendar<S> {
+    calendar: HolidayCalendar<S>,
+    shift: WeekendShift,
+}
+
+impl<S: ToString> ObservedCalendar<S> {
+    /// Creates a new ObservedCalendar, observing the Holidays in a calendar with a WeekendShift
+    pub fn new(calendar: HolidayCalendar<S>, shift: WeekendShift) -> Self {
+        ObservedCalendar { calendar, shift }
+    }
+
+    /// Returns the underlying HolidayCalendar
+    pub fn calendar(&self) -> &HolidayCalendar<S> {
+        &self.calendar
+    }
+
+    /// Returns the WeekendShift used to observe the Holidays
+    pub fn shift(&self) -> WeekendShift {
+        self.shift
+    }
+
+    /// Returns the first Holiday in the calendar observed on a given date
+    pub fn is_holiday(&self, date: &NaiveDate) -> Option<&Holiday<S>> {
+        self.calendar
+            .holidays()
+            .iter()
+            .find(|holiday| observed_occurrences_in_year(holiday, date.year(), self.shift).contains(date))
+    }
+
+    /// Returns the observed date of every Holiday in the calendar in a given year, sorted by date
+    pub fn holidays_in_year(&self, year: i32) -> Vec<(NaiveDate, &Holiday<S>)> {
+        let mut dates = self
+            .calendar
+            .holidays()
+            .iter()
+            .flat_map(|holiday| {
+                observed_occurrences_in_year(holiday, year, self.shift)
+                    .into_iter()
+                    .map(move |date| (date, holiday))
+            })
+            .collect::<Vec<_>>();
+        dates.sort_by_key(|(date, _holiday)| *date);
+        dates
+    }
+}
+
+#[test]
+fn observed_shift() {
+    let saturday = NaiveDate::from_ymd(2021, 12, 25);
+    let sunday = NaiveDate::from_ymd(2021, 12, 26);
+    assert_eq!(WeekendShift::NoShift.observe(saturday), saturday);
+    assert_eq!(WeekendShift::UsFederal.observe(saturday), NaiveDate::from_ymd(2021, 12, 24));
+    assert_eq!(WeekendShift::UsFederal.observe(sunday), NaiveDate::from_ymd(2021, 12, 27));
+    assert_eq!(WeekendShift::NextMonday.observe(saturday), NaiveDate::from_ymd(2021, 12, 27));
+    assert_eq!(WeekendShift::NextMonday.observe(sunday), NaiveDate::from_ymd(2021, 12, 27));
+}
+
+#[test]
+fn days_off_dedupe() {
+    use holidays::global::*;
+    let calendar = HolidayCalendar::from(vec![NEW_YEARS_DAY, CHRISTMAS_EVE, CHRISTMAS]);
+
+    // Christmas 2021 is a Saturday, observed on Christmas Eve, and New Year's Day 2022 is a
+    // Saturday, observed on December 31, 2021
+    let days_off = calendar.days_off_in_year(2021, WeekendShift::UsFederal);
+    assert_eq!(days_off.into_iter().collect::<Vec<_>>(), vec![
+        NaiveDate::from_ymd(2021, 1, 1),
+        NaiveDate::from_ymd(2021, 12, 24),
+        NaiveDate::from_ymd(2021, 12, 31),
+    ]);
+}
+
+#[test]
+fn status_on_actual_and_observed() {
+    use holidays::united_states::INDEPENDENCE_DAY;
+    let saturday = NaiveDate::from_ymd(2020, 7, 4);
+    let friday = NaiveDate::from_ymd(2020, 7, 3);
+
+    assert_eq!(INDEPENDENCE_DAY.status_on(&saturday, WeekendShift::UsFederal), TodayStatus::Today);
+    assert_eq!(INDEPENDENCE_DAY.status_on(&friday, WeekendShift::UsFederal), TodayStatus::ObservedToday);
+    assert_eq!(INDEPENDENCE_DAY.status_on(&friday, WeekendShift::NoShift), TodayStatus::NotToday);
+    assert_eq!(INDEPENDENCE_DAY.status_on(&friday.pred(), WeekendShift::UsFederal), TodayStatus::NotToday);
+}
+
+#[test]
+fn eq_observed_dates() {
+    use holidays::united_states::INDEPENDENCE_DAY;
+    let sunday = NaiveDate::from_ymd(2021, 7, 4);
+    let monday = NaiveDate::from_ymd(2021, 7, 5);
+
+    assert!(INDEPENDENCE_DAY.eq_observed(&sunday, WeekendShift::UsFederal));
+    assert!(INDEPENDENCE_DAY.eq_observed(&monday, WeekendShift::UsFederal));
+    assert!(!INDEPENDENCE_DAY.eq_observed(&monday, WeekendShift::NoShift));
+    assert_ne!(INDEPENDENCE_DAY, monday);
+}
+
+#[test]
+fn observed_calendar() {
+    use holidays::united_states::*;
+    let calendar = ObservedCalendar::new(holidays::united_states::calendar(), WeekendShift::UsFederal);
+
+    let observed = calendar.holidays_in_year(2021);
+    assert!(observed.contains(&(NaiveDate::from_ymd(2021, 7, 5), &INDEPENDENCE_DAY)));
+    assert!(!observed.iter().any(|(date, _holiday)| *date == NaiveDate::from_ymd(2021, 7, 4)));
+
+    assert_eq!(calendar.is_holiday(&NaiveDate::from_ymd(2021, 7, 5)), Some(&INDEPENDENCE_DAY));
+    assert_eq!(calendar.is_holiday(&NaiveDate::from_ymd(2021, 7, 4)), None);
+    assert_eq!(calendar.is_holiday(&NaiveDate::from_ymd(2021, 11, 25)), Some(&THANKSGIVING));
+}
+
+/// The US federal holidays, before Juneteenth was added
+#[cfg(test)]
+fn federal_calendar() -> HolidayCalendar<&'static str> {
+    use holidays::{global::*, united_states::*};
+    HolidayCalendar::from(vec![
+        NEW_YEARS_DAY,
+        MLKJ_DAY,
+        PRESIDENTS_DAY,
+        MEMORIAL_DAY,
+        INDEPENDENCE_DAY,
+        LABOR_DAY,
+        COLUMBUS_DAY,
+        VETERANS_DAY,
+        THANKSGIVING,
+        CHRISTMAS,
+    ])
+}
+
+#[test]
+fn total_days_off() {
+    let federal = federal_calendar();
+
+    // New Year's Day 2022 is observed on December 31, 2021
+    assert_eq!(federal.total_days_off(2021, WeekendShift::UsFederal), 11);
+    // Independence Day and Christmas 2021 fall on a weekend
+    assert_eq!(federal.total_days_off(2021, WeekendShift::NoShift), 8);
+}
+
+#[test]
+fn working_days_in_year() {
+    let federal = federal_calendar();
+    // 2021 has 261 weekdays
+    assert_eq!(federal.working_days_in_year(2021, WeekendShift::UsFederal), 250);
+    assert_eq!(federal.working_days_in_year(2021, WeekendShift::NoShift), 253);
+}
+
+#[test]
+fn long_weekends() {
+    let federal = federal_calendar();
+    let day = |month, day| NaiveDate::from_ymd(2021, month, day);
+    assert_eq!(federal.long_weekends(2021, WeekendShift::UsFederal), vec![
+        (day(1, 1), day(1, 3)),
+        (day(1, 16), day(1, 18)),
+        (day(2, 13), day(2, 15)),
+        (day(5, 29), day(5, 31)),
+        (day(7, 3), day(7, 5)),
+        (day(9, 4), day(9, 6)),
+        (day(10, 9), day(10, 11)),
+        (day(12, 24), day(12, 26)),
+        (day(12, 31), NaiveDate::from_ymd(2022, 1, 2)),
+    ]);
+}
+
+#[test]
+fn closures_in_year() {
+    use holidays::global::CHRISTMAS_EVE;
+    let mut calendar = federal_calendar();
+    calendar.push(CHRISTMAS_EVE);
+    let day = |month, day| NaiveDate::from_ymd(2020, month, day);
+
+    let closures = calendar.closures_in_year(2020, WeekendShift::UsFederal);
+    // Thanksgiving is a single day closure
+    assert!(closures.contains(&(day(11, 26), day(11, 26))));
+    // Christmas Eve and Christmas on Thursday and Friday, merged with the weekend
+    assert!(closures.contains(&(day(12, 24), day(12, 27))));
+    // Weekends without a Holiday are not closures
+    assert!(!closures.contains(&(day(12, 19), day(12, 20))));
+    assert_eq!(closures.len(), 10);
+}
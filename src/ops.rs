@@ -0,0 +1,55 @@
+use crate::*;
+
+use std::ops::{Add, Sub};
+
+impl Add<i32> for HolidayDate {
+    type Output = HolidayDate;
+    /// Shift the HolidayDate later by a number of days
+    fn add(self, days: i32) -> HolidayDate {
+        self.shift_by(days)
+    }
+}
+
+impl Sub<i32> for HolidayDate {
+    type Output = HolidayDate;
+    /// Shift the HolidayDate earlier by a number of days
+    fn sub(self, days: i32) -> HolidayDate {
+        self.shift_by(days.saturating_neg())
+    }
+}
+
+impl<S: ToString> Add<i32> for Holiday<S> {
+    type Output = Holiday<S>;
+    /// Shift the Holiday later by a number of days. See `Holiday::shift_by`.
+    fn add(self, days: i32) -> Holiday<S> {
+        self.shift_by(days)
+    }
+}
+
+impl<S: ToString> Sub<i32> for Holiday<S> {
+    type Output = Holiday<S>;
+    /// Shift the Holiday earlier by a number of days. See `Holiday::shift_by`.
+    fn sub(self, days: i32) -> Holiday<S> {
+        self.shift_by(days.saturating_neg())
+    }
+}
+
+#[test]
+fn shift_arithmetic() {
+    use holidays::{global::CHRISTMAS, united_states::THANKSGIVING};
+
+    let black_friday = THANKSGIVING.date + 1;
+    let manual = HolidayDate::Offset { base: Box::new(THANKSGIVING.date), days: 1 };
+    assert_eq!(black_friday.resolve_in_year(2021), manual.resolve_in_year(2021));
+    assert_eq!(black_friday.resolve_in_year(2021), Some(NaiveDate::from_ymd(2021, 11, 26)));
+
+    let wednesday = THANKSGIVING - 1;
+    assert_eq!(wednesday.in_year(2021), NaiveDate::from_ymd(2021, 11, 24));
+    assert_eq!((CHRISTMAS - 1).in_year(2021), NaiveDate::from_ymd(2021, 12, 24));
+    assert_eq!((CHRISTMAS + 1).in_year(2021), NaiveDate::from_ymd(2021, 12, 26));
+
+    // Shifts beyond the representable date range saturate rather than overflow
+    assert_eq!((CHRISTMAS - i32::MIN).occurrences_in_year(2021), vec![]);
+    assert_eq!((THANKSGIVING + i32::MAX + 1).occurrences_in_year(2021), vec![]);
+    assert_eq!((THANKSGIVING.date - i32::MIN).resolve_in_year(2021), None);
+}
@@ -0,0 +1,137 @@
+//! Pack a `HolidayDate` into a `u32`, as a compact alternative to text serialization.
+//!
+//! The lowest 4 bits hold the variant tag. The remaining bits depend on the variant:
+//!
+//! | Variant           | Bits 4..8 | Bits 8..13 | Bits 13..16 | Bits 16..24 |
+//! |-------------------|-----------|------------|-------------|-------------|
+//! | `FixedDate`       | month     | day        |             |             |
+//! | `NthDate`         | month     | nth        | weekday     |             |
+//! | `WeekdayRelative` | month     | day        | weekday     | count (i8)  |
+//! | `WeeklySeries`    | month     | day        | weekday     | count (u8)  |
+//!
+//! `WeeklySeries` also stores its direction in bit 24: 0 is before the anchor, 1 is after.
+//! `Hijri` uses the same layout as `FixedDate`, with the Islamic month and day.
+//! `FromYearEnd` stores its offset in bits 4..20 and `EasterRelative` its offset as an `i16` in
+//! bits 4..20. Weekdays are numbered from Monday = 0.
+
+use crate::*;
+
+use std::convert::TryFrom;
+
+const FIXED: u32 = 1;
+const NTH: u32 = 2;
+const WEEKDAY_RELATIVE: u32 = 3;
+const FROM_YEAR_END: u32 = 4;
+const HIJRI: u32 = 5;
+const EASTER_RELATIVE: u32 = 6;
+const WEEKLY_SERIES: u32 = 7;
+
+impl HolidayDate {
+    /// Pack the date rule into a `u32`. Returns `None` for rules that do not fit, such as
+    /// offsets from another rule.
+    pub fn to_packed(&self) -> Option<u32> {
+        match self {
+            FixedDate(dom) => Some(FIXED | pack_day_of_month(dom)),
+            NthDate(nth) => Some(
+                NTH | (nth.month as u32) << 4
+                    | (nth.nth as u32) << 8
+                    | nth.weekday.num_days_from_monday() << 13,
+            ),
+            WeekdayRelative { weekday, anchor, count } if i8::try_from(*count).is_ok() => Some(
+                WEEKDAY_RELATIVE | pack_day_of_month(anchor)
+                    | weekday.num_days_from_monday() << 13
+                    | (*count as i8 as u8 as u32) << 16,
+            ),
+            FromYearEnd { offset_days } if *offset_days <= 0xFFFF => Some(FROM_YEAR_END | offset_days << 4),
+            Hijri { month, day } if (1..=12).contains(month) && (1..=30).contains(day) => {
+                Some(HIJRI | month << 4 | day << 8)
+            }
+            EasterRelative { days } if i16::try_from(*days).is_ok() => {
+                Some(EASTER_RELATIVE | (*days as i16 as u16 as u32) << 4)
+            }
+            WeeklySeries { weekday, count, anchor, direction } if *count <= 0xFF => Some(
+                WEEKLY_SERIES | pack_day_of_month(anchor)
+                    | weekday.num_days_from_monday() << 13
+                    | count << 16
+                    | ((*direction == SeriesDirection::After) as u32) << 24,
+            ),
+            _ => None,
+        }
+    }
+
+    /// Unpack a date rule packed with `to_packed`. Returns `None` if the value is not a valid
+    /// packed date rule.
+    pub fn from_packed(packed: u32) -> Option<HolidayDate> {
+        let bits = |start: u32, len: u32| (packed >> start) & ((1 << len) - 1);
+        let month = || Some(bits(4, 4)).filter(|month| (1..=12).contains(month)).map(Month::from);
+        let day = || Some(bits(8, 5)).filter(|day| *day >= 1);
+        let weekday = || weekday_from_monday(bits(13, 3));
+
+        let (date, used_bits) = match bits(0, 4) {
+            FIXED => (FixedDate(DayOfMonth { month: month()?, day: day()? }), 13),
+            NTH => {
+                let nth = Some(bits(8, 5)).filter(|nth| (1..=6).contains(nth))?;
+                (NthDate(NthWeekdayOfMonth::new(nth, weekday()?, month()?)), 16)
+            }
+            WEEKDAY_RELATIVE => (
+                WeekdayRelative {
+                    weekday: weekday()?,
+                    anchor: DayOfMonth { month: month()?, day: day()? },
+                    count: bits(16, 8) as u8 as i8 as i32,
+                },
+                24,
+            ),
+            FROM_YEAR_END => (FromYearEnd { offset_days: bits(4, 16) }, 20),
+            HIJRI => (Hijri { month: month()? as u32, day: day()? }, 13),
+            EASTER_RELATIVE => (EasterRelative { days: bits(4, 16) as u16 as i16 as i32 }, 20),
+            WEEKLY_SERIES => (
+                WeeklySeries {
+                    weekday: weekday()?,
+                    count: bits(16, 8),
+                    anchor: DayOfMonth { month: month()?, day: day()? },
+                    direction: if bits(24, 1) == 1 { SeriesDirection::After } else { SeriesDirection::Before },
+                },
+                25,
+            ),
+            _ => return None,
+        };
+
+        if packed >> used_bits == 0 {
+            Some(date)
+        } else {
+            None
+        }
+    }
+}
+
+fn pack_day_of_month(dom: &DayOfMonth) -> u32 {
+    (dom.month as u32) << 4 | dom.day << 8
+}
+
+fn weekday_from_monday(n: u32) -> Option<Weekday> {
+    Some(match n {
+        0 => Weekday::Mon,
+        1 => Weekday::Tue,
+        2 => Weekday::Wed,
+        3 => Weekday::Thu,
+        4 => Weekday::Fri,
+        5 => Weekday::Sat,
+        6 => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+#[test]
+fn packed_round_trip() {
+    for holiday in holidays::united_states::ALL.iter().chain(holidays::global::ALL) {
+        let packed = holiday.date.to_packed().expect("built-in date rules should pack");
+        assert_eq!(HolidayDate::from_packed(packed), Some(holiday.date.clone()));
+    }
+
+    let year_end = FromYearEnd { offset_days: 1 };
+    assert_eq!(HolidayDate::from_packed(year_end.to_packed().unwrap()), Some(year_end));
+
+    assert_eq!(holidays::united_states::THANKSGIVING.shift_by(1).date.to_packed(), None);
+    assert_eq!(HolidayDate::from_packed(0), None);
+    assert_eq!(HolidayDate::from_packed(FIXED | 13 << 4 | 1 << 8), None);
+}
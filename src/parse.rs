@@ -0,0 +1,280 @@
+//! Parse date descriptions like "4th Thursday in November" or "December 25"
+
+use crate::*;
+
+use std::str::FromStr;
+
+impl FromStr for Month {
+    type Err = HolidayError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match normalize(s).as_str() {
+            "jan" | "january"            => January,
+            "feb" | "february"           => February,
+            "mar" | "march"              => March,
+            "apr" | "april"              => April,
+            "may"                        => May,
+            "jun" | "june"               => June,
+            "jul" | "july"               => July,
+            "aug" | "august"             => August,
+            "sep" | "sept" | "september" => September,
+            "oct" | "october"            => October,
+            "nov" | "november"           => November,
+            "dec" | "december"           => December,
+            _ => return Err(HolidayError::InvalidInput(s.into())),
+        })
+    }
+}
+
+impl FromStr for NthWeekday {
+    type Err = HolidayError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match normalize(s).as_str() {
+            "1" | "1st" | "first"  => First,
+            "2" | "2nd" | "second" => Second,
+            "3" | "3rd" | "third"  => Third,
+            "4" | "4th" | "fourth" => Fourth,
+            "5" | "5th" | "fifth"  => Fifth,
+            "last"                 => Last,
+            _ => return Err(HolidayError::InvalidInput(s.into())),
+        })
+    }
+}
+
+impl FromStr for NthWeekdayOfMonth {
+    type Err = HolidayError;
+    /// Parse a description like "4th Thursday in November" or "last Monday of May"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || HolidayError::InvalidInput(s.into());
+        match words(s).as_slice() {
+            [nth, weekday, month] => Ok(NthWeekdayOfMonth::new(
+                nth.parse::<NthWeekday>().map_err(|_| invalid())?,
+                parse_weekday(weekday).map_err(|_| invalid())?,
+                month.parse::<Month>().map_err(|_| invalid())?,
+            )),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl FromStr for DayOfMonth {
+    type Err = HolidayError;
+    /// Parse a description like "December 25", "Dec 25th", or "25 December". Days the month never
+    /// has, like "April 31", are rejected; "February 29" is allowed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || HolidayError::InvalidInput(s.into());
+        let (month, day) = match words(s).as_slice() {
+            [first, second] => match first.parse::<Month>() {
+                Ok(month) => (month, parse_day(second).ok_or_else(invalid)?),
+                Err(_) => (
+                    second.parse::<Month>().map_err(|_| invalid())?,
+                    parse_day(first).ok_or_else(invalid)?,
+                ),
+            },
+            _ => return Err(invalid()),
+        };
+        if day > month.max_days() {
+            return Err(invalid());
+        }
+
+        Ok(DayOfMonth::new(day, month))
+    }
+}
+
+impl FromStr for HolidayDate {
+    type Err = HolidayError;
+    /// Parse either a fixed date or an nth weekday of the month description
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<NthWeekdayOfMonth>()
+            .map(HolidayDate::NthDate)
+            .or_else(|_| s.parse::<DayOfMonth>().map(HolidayDate::FixedDate))
+    }
+}
+
+/// Parse a named holiday or a date description followed by a year into a date.
+/// Example: "4th Thursday in November 2021", "December 25, 2021", or "Christmas 2021"
+pub fn parse_date(s: &str) -> Result<NaiveDate, HolidayError> {
+    let invalid = || HolidayError::InvalidInput(s.into());
+    let s = s.trim().trim_end_matches(',');
+    let (description, year) = s
+        .rfind(|c: char| c.is_whitespace() || c == ',')
+        .map(|i| s.split_at(i))
+        .ok_or_else(invalid)?;
+    let year = year.trim_start_matches(',').trim().parse::<i32>().map_err(|_| invalid())?;
+    let holiday = description.trim_end_matches(',').parse::<Holiday<String>>()?;
+
+    holiday.date.resolve_in_year(year).ok_or_else(invalid)
+}
+
+/// Parse each line of the input as a named holiday or a date description, keeping one result per
+/// line so errors can be reported against the line that caused them
+pub fn parse_many(input: &str) -> Vec<Result<Holiday<String>, HolidayError>> {
+    input.lines().map(|line| line.trim().parse::<Holiday<String>>()).collect()
+}
+
+impl FromStr for Direction {
+    type Err = HolidayError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "next" => Direction::Next,
+            "this" => Direction::This,
+            "last" => Direction::Last,
+            _ => return Err(HolidayError::InvalidInput(s.into())),
+        })
+    }
+}
+
+/// Parse a relative weekday like "this Friday", "next Friday", or "last Friday" from a given date.
+/// See `relative_weekday`.
+pub fn parse_relative_weekday(s: &str, from: NaiveDate) -> Result<NaiveDate, HolidayError> {
+    let invalid = || HolidayError::InvalidInput(s.into());
+    match words(s).as_slice() {
+        [direction, weekday] => Ok(relative_weekday(
+            parse_weekday(weekday).map_err(|_| invalid())?,
+            from,
+            direction.parse::<Direction>().map_err(|_| invalid())?,
+        )),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parse an nth weekday without a month like "last Friday" or "2nd Tuesday", resolving it in the
+/// year and month of a given date
+pub fn parse_nth_in_month(s: &str, month: NaiveDate) -> Result<NaiveDate, HolidayError> {
+    let invalid = || HolidayError::InvalidInput(s.into());
+    match words(s).as_slice() {
+        [nth, weekday] => NthWeekdayOfMonth::new(
+            nth.parse::<NthWeekday>().map_err(|_| invalid())?,
+            parse_weekday(weekday).map_err(|_| invalid())?,
+            month.month(),
+        )
+        .to_date(month.year())
+        .filter(|date| date.month() == month.month())
+        .ok_or_else(invalid),
+        _ => Err(invalid()),
+    }
+}
+
+/// Determine if the input looks like a date description, i.e., it contains a number, a month, or
+/// a weekday, so a parse error should be reported as a malformed description rather than an
+/// unknown holiday
+pub(crate) fn looks_like_description(s: &str) -> bool {
+    words(s).iter().any(|word| {
+        word.chars().any(|c| c.is_ascii_digit())
+            || word.parse::<Month>().is_ok()
+            || word.parse::<NthWeekday>().is_ok()
+            || parse_weekday(word).is_ok()
+    })
+}
+
+/// Parse a weekday, accepting the abbreviations chrono does not, like "Tues" or "Thurs"
+fn parse_weekday(s: &str) -> Result<Weekday, HolidayError> {
+    match normalize(s).as_str() {
+        "tues" => Ok(Weekday::Tue),
+        "weds" => Ok(Weekday::Wed),
+        "thur" | "thurs" => Ok(Weekday::Thu),
+        word => word.parse::<Weekday>().map_err(|_| HolidayError::InvalidInput(s.into())),
+    }
+}
+
+/// Lowercase a word, dropping surrounding whitespace and a trailing period, e.g., " Nov. "
+fn normalize(s: &str) -> String {
+    s.trim().trim_end_matches('.').to_lowercase()
+}
+
+/// Split a description into lowercase words, dropping filler words and punctuation
+fn words(s: &str) -> Vec<String> {
+    s.split(|c: char| c.is_whitespace() || c == ',')
+        .map(str::to_lowercase)
+        .filter(|word| !matches!(word.as_str(), "" | "in" | "of" | "the"))
+        .collect()
+}
+
+/// Parse a day of the month like "25" or "25th"
+fn parse_day(s: &str) -> Option<u32> {
+    s.trim_end_matches(|c: char| c.is_alphabetic())
+        .parse::<u32>()
+        .ok()
+        .filter(|day| (1..=31).contains(day))
+}
+
+#[test]
+fn parse_dates() {
+    assert_eq!(parse_date("4th Thursday in November 2021"), Ok(NaiveDate::from_ymd(2021, 11, 25)));
+    assert_eq!(parse_date("December 25 2021"), Ok(NaiveDate::from_ymd(2021, 12, 25)));
+    assert_eq!(parse_date("December 25, 2021"), Ok(NaiveDate::from_ymd(2021, 12, 25)));
+    assert_eq!(parse_date("Christmas 2021"), Ok(NaiveDate::from_ymd(2021, 12, 25)));
+    assert!(parse_date("February 29 2021").is_err());
+    assert_eq!(parse_date("February 29 2020"), Ok(NaiveDate::from_ymd(2020, 2, 29)));
+    assert!(parse_date("December 25").is_err());
+}
+
+#[test]
+fn parse_abbreviations() {
+    assert_eq!("Sept.".parse::<Month>(), Ok(September));
+    assert_eq!("  November  ".parse::<Month>(), Ok(November));
+    assert_eq!("Nov.".parse::<Month>(), Ok(November));
+    assert_eq!(parse_weekday("Thurs"), Ok(Weekday::Thu));
+    assert_eq!(parse_weekday("Thur."), Ok(Weekday::Thu));
+    assert_eq!(" 4th ".parse::<NthWeekday>(), Ok(Fourth));
+    assert_eq!(
+        "4th  Thurs. in  Nov.".parse::<HolidayDate>(),
+        Ok(HolidayDate::NthDate(NthWeekdayOfMonth::new(Fourth, Weekday::Thu, November)))
+    );
+    assert_eq!("Sept. 1".parse::<DayOfMonth>(), Ok(DayOfMonth::new(1, September)));
+}
+
+#[test]
+fn parse_many_lines() {
+    let parsed = parse_many("Christmas\nDecember 32\n4th Thursday in November\n");
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed[0].as_ref().map(|holiday| holiday.name().as_str()), Ok("Christmas"));
+    assert_eq!(parsed[1], Err(HolidayError::InvalidInput("December 32".into())));
+    assert_eq!(parsed[2].as_ref().map(|holiday| holiday.name().as_str()), Ok("4th Thursday in November"));
+
+    let parsed = parse_many("February 30\nApril 31\n31 June\nFebruary 29");
+    assert_eq!(parsed[0], Err(HolidayError::InvalidInput("February 30".into())));
+    assert_eq!(parsed[1], Err(HolidayError::InvalidInput("April 31".into())));
+    assert_eq!(parsed[2], Err(HolidayError::InvalidInput("31 June".into())));
+    assert!(parsed[3].is_ok());
+}
+
+#[test]
+fn parse_relative_weekdays() {
+    let wednesday = NaiveDate::from_ymd(2021, 3, 3);
+    assert_eq!(parse_relative_weekday("This Friday", wednesday), Ok(NaiveDate::from_ymd(2021, 3, 5)));
+    assert_eq!(parse_relative_weekday("next fri", wednesday), Ok(NaiveDate::from_ymd(2021, 3, 12)));
+    assert_eq!(parse_relative_weekday("Last Friday", wednesday), Ok(NaiveDate::from_ymd(2021, 2, 26)));
+    assert!(parse_relative_weekday("first Friday", wednesday).is_err());
+    assert!(parse_relative_weekday("this", wednesday).is_err());
+}
+
+#[test]
+fn parse_nth_without_month() {
+    let may = NaiveDate::from_ymd(2021, 5, 12);
+    assert_eq!(parse_nth_in_month("last Friday", may), Ok(NaiveDate::from_ymd(2021, 5, 28)));
+    assert_eq!(parse_nth_in_month("2nd tue", may), Ok(NaiveDate::from_ymd(2021, 5, 11)));
+    assert!(parse_nth_in_month("5th Tuesday", may).is_err());
+    assert!(parse_nth_in_month("last Friday in May", may).is_err());
+}
+
+#[test]
+fn parse_descriptions() {
+    assert_eq!(
+        "4th Thursday in November".parse::<HolidayDate>(),
+        Ok(HolidayDate::NthDate(NthWeekdayOfMonth::new(Fourth, Weekday::Thu, November)))
+    );
+    assert_eq!(
+        "last monday of may".parse::<HolidayDate>(),
+        Ok(HolidayDate::NthDate(NthWeekdayOfMonth::new(Last, Weekday::Mon, May)))
+    );
+    assert_eq!(
+        "December 25".parse::<HolidayDate>(),
+        Ok(HolidayDate::FixedDate(DayOfMonth::new(25, December)))
+    );
+    assert_eq!(
+        "25th of Dec".parse::<HolidayDate>(),
+        Ok(HolidayDate::FixedDate(DayOfMonth::new(25, December)))
+    );
+    assert!("December 32".parse::<HolidayDate>().is_err());
+    assert!("asdf".parse::<HolidayDate>().is_err());
+}
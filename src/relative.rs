@@ -0,0 +1,44 @@
+//! # Relative weekdays
+//!
+//! Resolve relative weekday descriptions like "this Friday", "next Friday", or "last Friday"
+
+use crate::*;
+
+/// Which occurrence of a weekday relative to a given date
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    /// The occurrence one week after "this" weekday
+    Next,
+    /// The coming occurrence, including the given date
+    This,
+    /// The most recent occurrence, excluding the given date
+    Last,
+}
+
+/// Find a weekday relative to a given date.
+/// From Wednesday, March 3, 2021: "this Friday" is March 5, "next Friday" is March 12, and "last
+/// Friday" is February 26.
+pub fn relative_weekday(weekday: Weekday, from: NaiveDate, direction: Direction) -> NaiveDate {
+    let days_ahead = (weekday.num_days_from_sunday() + 7 - from.weekday().num_days_from_sunday()) % 7;
+    let this = from + chrono::Duration::days(days_ahead as i64);
+    match direction {
+        Direction::Next => this + chrono::Duration::weeks(1),
+        Direction::This => this,
+        Direction::Last => {
+            let days_behind = (from.weekday().num_days_from_sunday() + 6 - weekday.num_days_from_sunday()) % 7 + 1;
+            from - chrono::Duration::days(days_behind as i64)
+        }
+    }
+}
+
+#[test]
+fn relative_fridays() {
+    let wednesday = NaiveDate::from_ymd(2021, 3, 3);
+    assert_eq!(relative_weekday(Weekday::Fri, wednesday, Direction::This), NaiveDate::from_ymd(2021, 3, 5));
+    assert_eq!(relative_weekday(Weekday::Fri, wednesday, Direction::Next), NaiveDate::from_ymd(2021, 3, 12));
+    assert_eq!(relative_weekday(Weekday::Fri, wednesday, Direction::Last), NaiveDate::from_ymd(2021, 2, 26));
+
+    assert_eq!(relative_weekday(Weekday::Wed, wednesday, Direction::This), wednesday);
+    assert_eq!(relative_weekday(Weekday::Wed, wednesday, Direction::Next), NaiveDate::from_ymd(2021, 3, 10));
+    assert_eq!(relative_weekday(Weekday::Wed, wednesday, Direction::Last), NaiveDate::from_ymd(2021, 2, 24));
+}
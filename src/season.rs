@@ -0,0 +1,77 @@
+//! # Astronomical seasons
+//!
+//! Seasons of the northern hemisphere bounded by the equinoxes and solstices, approximated with
+//! the polynomial formulas from Jean Meeus' _Astronomical Algorithms_ (accurate to within minutes
+//! for the years 1000 to 3000).
+
+use crate::*;
+
+/// A season of the northern hemisphere
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Season {
+    /// From the March equinox to the June solstice
+    Spring,
+    /// From the June solstice to the September equinox
+    Summer,
+    /// From the September equinox to the December solstice
+    Autumn,
+    /// From the December solstice to the March equinox
+    Winter,
+}
+
+/// The Julian ephemeris day of 2000-01-01 00:00
+const J2000_MIDNIGHT: f64 = 2_451_544.5;
+
+impl Season {
+    /// The date (UTC) of the equinox or solstice that starts the season in a given year
+    pub fn start_in_year(self, year: i32) -> NaiveDate {
+        let y = (year as f64 - 2000.0) / 1000.0;
+        let [a, b, c, d, e] = match self {
+            Season::Spring => [2_451_623.809_84, 365_242.374_04, 0.051_69, -0.004_11, -0.000_57],
+            Season::Summer => [2_451_716.567_67, 365_241.626_03, 0.003_25, 0.008_88, -0.000_30],
+            Season::Autumn => [2_451_810.217_15, 365_242.017_67, -0.115_75, 0.003_37, 0.000_78],
+            Season::Winter => [2_451_900.059_52, 365_242.740_49, -0.062_23, -0.008_23, 0.000_32],
+        };
+        let jde = a + y * (b + y * (c + y * (d + y * e)));
+        NaiveDate::from_ymd_opt(2000, 1, 1).unwrap() + chrono::Duration::days((jde - J2000_MIDNIGHT).floor() as i64)
+    }
+
+    /// The astronomical season of a given date, counting the day of an equinox or solstice as
+    /// the first day of the new season
+    pub fn of(date: &NaiveDate) -> Season {
+        let year = date.year();
+        if *date >= Season::Winter.start_in_year(year) {
+            Season::Winter
+        } else if *date >= Season::Autumn.start_in_year(year) {
+            Season::Autumn
+        } else if *date >= Season::Summer.start_in_year(year) {
+            Season::Summer
+        } else if *date >= Season::Spring.start_in_year(year) {
+            Season::Spring
+        } else {
+            Season::Winter
+        }
+    }
+}
+
+impl<S: ToString> Holiday<S> {
+    /// The astronomical season in which the Holiday falls in a given year
+    pub fn astronomical_season_in_year(&self, year: i32) -> Season {
+        Season::of(&self.in_year(year))
+    }
+}
+
+#[test]
+fn astronomical_seasons() {
+    assert_eq!(Season::Spring.start_in_year(2021), NaiveDate::from_ymd(2021, 3, 20));
+    assert_eq!(Season::Summer.start_in_year(2021), NaiveDate::from_ymd(2021, 6, 21));
+    assert_eq!(Season::Autumn.start_in_year(2021), NaiveDate::from_ymd(2021, 9, 22));
+    assert_eq!(Season::Winter.start_in_year(2021), NaiveDate::from_ymd(2021, 12, 21));
+
+    assert_eq!(holidays::global::CHRISTMAS.astronomical_season_in_year(2021), Season::Winter);
+    assert_eq!(Season::of(&NaiveDate::from_ymd(2021, 12, 20)), Season::Autumn);
+    assert_eq!(Season::of(&NaiveDate::from_ymd(2021, 12, 21)), Season::Winter);
+    assert_eq!(Season::of(&NaiveDate::from_ymd(2021, 9, 21)), Season::Summer);
+    assert_eq!(Season::of(&NaiveDate::from_ymd(2021, 9, 28)), Season::Autumn);
+    assert_eq!(Season::of(&NaiveDate::from_ymd(2021, 1, 1)), Season::Winter);
+}
@@ -0,0 +1,91 @@
+//! # Holiday spans
+//!
+//! Holidays that last more than one day, like a long weekend starting on Thanksgiving
+
+use crate::*;
+
+/// A Holiday lasting a number of consecutive days, starting on each occurrence of the Holiday
+#[derive(Debug, Clone)]
+pub struct HolidaySpan<S> {
+    holiday: Holiday<S>,
+    days: u32,
+}
+
+impl<S: ToString> HolidaySpan<S> {
+    /// Creates a new HolidaySpan lasting a number of days. A span lasts at least one day.
+    pub fn new(holiday: Holiday<S>, days: u32) -> Self {
+        HolidaySpan { holiday, days: days.max(1) }
+    }
+
+    /// Returns the Holiday that starts the span
+    pub fn holiday(&self) -> &Holiday<S> {
+        &self.holiday
+    }
+
+    /// Returns the number of days the span lasts
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// The first and last dates (inclusive) of every span with a day in a given year, clipped to
+    /// the year. Includes spans starting in the previous year, e.g., one starting December 31.
+    pub fn ranges_in_year(&self, year: i32) -> Vec<(NaiveDate, NaiveDate)> {
+        let (first_day, last_day) = match (NaiveDate::from_ymd_opt(year, 1, 1), NaiveDate::from_ymd_opt(year, 12, 31)) {
+            (Some(first_day), Some(last_day)) => (first_day, last_day),
+            _ => return Vec::new(),
+        };
+
+        (year - 1..=year)
+            .flat_map(|y| self.holiday.occurrences_in_year(y))
+            .filter_map(|start| {
+                let end = start.checked_add_signed(chrono::Duration::days(self.days as i64 - 1))?;
+                if end < first_day || start > last_day {
+                    None
+                } else {
+                    Some((start.max(first_day), end.min(last_day)))
+                }
+            })
+            .collect()
+    }
+
+    /// Determine if two spans share any day in a given year
+    pub fn overlaps<T: ToString>(&self, other: &HolidaySpan<T>, year: i32) -> bool {
+        let others = other.ranges_in_year(year);
+        self.ranges_in_year(year).iter().any(|(start, end)| {
+            others
+                .iter()
+                .any(|(other_start, other_end)| start <= other_end && other_start <= end)
+        })
+    }
+}
+
+#[test]
+fn span_overlaps() {
+    use holidays::{global::*, united_states::*};
+    let thanksgiving = HolidaySpan::new(THANKSGIVING, 3);
+    let black_friday = HolidaySpan::new(THANKSGIVING.shift_by(1).with_name("Black Friday"), 2);
+    let christmas = HolidaySpan::new(CHRISTMAS, 2);
+
+    assert_eq!(
+        thanksgiving.ranges_in_year(2020),
+        vec![(NaiveDate::from_ymd(2020, 11, 26), NaiveDate::from_ymd(2020, 11, 28))]
+    );
+    assert!(thanksgiving.overlaps(&black_friday, 2020));
+    assert!(black_friday.overlaps(&thanksgiving, 2020));
+    assert!(!thanksgiving.overlaps(&christmas, 2020));
+
+    // October 31 through November 1
+    let halloween = HolidaySpan::new(HALLOWEEN, 2);
+    assert!(halloween.overlaps(&HolidaySpan::new(Holiday::new_fixed("All Saints' Day", November, 1), 1), 2020));
+
+    // December 31 through January 1 of the next year
+    let new_years = HolidaySpan::new(NEW_YEARS_EVE, 2);
+    assert_eq!(
+        new_years.ranges_in_year(2021),
+        vec![
+            (NaiveDate::from_ymd(2021, 1, 1), NaiveDate::from_ymd(2021, 1, 1)),
+            (NaiveDate::from_ymd(2021, 12, 31), NaiveDate::from_ymd(2021, 12, 31)),
+        ]
+    );
+    assert!(new_years.overlaps(&HolidaySpan::new(NEW_YEARS_DAY, 1), 2021));
+}